@@ -19,8 +19,9 @@
 // main.rs -- For testing functions from Chapter 2 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch02;
-use ch02::*;
+extern crate fm2gp;
+use fm2gp::ch02::*;
+use fm2gp::trace::*;
 
 fn main() {
     println!("multiply0(7, 8) = {}", multiply0(7, 8));
@@ -34,4 +35,62 @@ fn main() {
     println!("multiply2(7, 8) = {}", multiply2(7, 8));
     println!("multiply3(7, 8) = {}", multiply3(7, 8));
     println!("multiply4(7, 8) = {}", multiply4(7, 8));
+    println!("multiply4(7u8, 8u8) = {}", multiply4(7u8, 8u8));
+    println!("multiply4(7u64, 8i128) = {}", multiply4(7u64, 8i128));
+    println!(
+        "checked_mult_acc4(0, 7, 8) = {:?}",
+        checked_mult_acc4(0, 7, 8)
+    );
+    println!("checked_multiply4(0, 8) = {:?}", checked_multiply4(0, 8));
+    println!("checked_multiply4(-7, 8) = {:?}", checked_multiply4(-7, 8));
+    println!(
+        "checked_multiply4(100i8, 2i8) = {:?}",
+        checked_multiply4(100i8, 2i8)
+    );
+    println!(
+        "wrapping_multiply4(100i8, 2i8) = {}",
+        wrapping_multiply4(100i8, 2i8)
+    );
+    println!(
+        "wrapping_mult_acc4(1u8, -1, 1u8) = {}",
+        wrapping_mult_acc4(1u8, -1, 1u8)
+    );
+    println!(
+        "saturating_multiply4(-100i8, 2i8) = {}",
+        saturating_multiply4(-100i8, 2i8)
+    );
+    println!(
+        "saturating_mult_acc4(-128i8, 100i8, 2i8) = {}",
+        saturating_mult_acc4(-128i8, 100i8, 2i8)
+    );
+    println!("divide0(45, 6) = {:?}", divide0(45, 6));
+    println!("divide1(45, 6) = {:?}", divide1(45, 6));
+    println!(
+        "divide_acc0(0, 45, 24, 6) = {:?}",
+        divide_acc0(0, 45, 24, 6)
+    );
+    println!(
+        "divide_acc1(0, 45, 24, 6) = {:?}",
+        divide_acc1(0, 45, 24, 6)
+    );
+    println!(
+        "divide_acc2(0, 45, 24, 6) = {:?}",
+        divide_acc2(0, 45, 24, 6)
+    );
+    println!(
+        "divide_acc3(0, 45, 24, 6) = {:?}",
+        divide_acc3(0, 45, 24, 6)
+    );
+    println!(
+        "divide_acc4(0, 45, 24, 6) = {:?}",
+        divide_acc4(0, 45, 24, 6)
+    );
+    println!("divide2(45, 6) = {:?}", divide2(45, 6));
+    println!("divide3(45, 6) = {:?}", divide3(45, 6));
+    println!("multiply4_trace(41, 59):");
+    print!("{}", multiply4_trace(41, 59));
+    println!("mult_acc4_trace(0, 41, 59):");
+    print!("{}", mult_acc4_trace(0, 41, 59).to_markdown());
+    println!("multiply4_trace(12, 5):");
+    println!("{}", multiply4_trace(12, 5).to_json());
 }
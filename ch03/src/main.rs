@@ -19,8 +19,13 @@
 // ch03.cpp -- For testing functions from Chapter 3 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch03;
-use ch03::*;
+extern crate fm2gp;
+use fm2gp::ch03::*;
+use fm2gp::factor_sieve::*;
+use fm2gp::primes::*;
+use fm2gp::segmented_sieve::*;
+use fm2gp::special_numbers::*;
+use fm2gp::wheel_sieve::*;
 
 fn print_sieve<'a, I>(first: I)
 where
@@ -46,5 +51,144 @@ fn main() {
     sift(&mut v);
     println!("sift(begin(v), 500):");
     print_sieve(&v);
+    let mut bits = BitVector::new(500, false);
+    sift(&mut bits);
+    println!(
+        "sift(BitVector, 500) == sift(begin(v), 500): {} ({} bytes instead of {})",
+        bits.iter().eq(v.iter().cloned()),
+        bits.bytes(),
+        v.len()
+    );
+    let sifted: Vec<u64> = std::iter::once(2)
+        .chain(
+            v.iter()
+                .enumerate()
+                .filter(|&(_, &x)| x)
+                .map(|(i, _)| 2 * i as u64 + 3),
+        )
+        .collect();
+    for &threads in &[1, 3, 8] {
+        let mut parallel = vec![false; 100_000];
+        sift_parallel(&mut parallel, threads);
+        let mut serial = BitVector::new(100_000, false);
+        sift(&mut serial);
+        let mut parallel_bits = BitVector::new(100_000, false);
+        sift_parallel(&mut parallel_bits, threads);
+        println!(
+            "sift_parallel(100000, {} threads) == sift(100000): {}",
+            threads,
+            parallel_bits == serial && serial.iter().eq(parallel.iter().cloned())
+        );
+    }
+    for wheel in &[wheel30(), wheel210()] {
+        let mut table = vec![false; wheel.table_len(1001)];
+        sift_wheel(&mut table, wheel);
+        println!(
+            "sift_wheel(mod {:?}, 1001) == sift(begin(v), 500): {} ({} entries instead of {})",
+            wheel.primes(),
+            wheel.primes_in(&table, 1001) == sifted,
+            table.len(),
+            v.len()
+        );
+    }
+    let mut table = BitVector::new(wheel210().table_len(10_000_000), false);
+    sift210(&mut table);
+    println!(
+        "sift210(BitVector, 10^7) primes: {} (prime_pi(10^7) = {})",
+        4 + table.count_ones(),
+        prime_pi(10_000_000)
+    );
+    let segmented: Vec<u64> = segmented_primes_with_segment_size(0, 1002, 64).collect();
+    println!(
+        "segmented_primes(0, 1002) == sift(begin(v), 500): {}",
+        segmented == sifted
+    );
+    let listed: Vec<String> = segmented_primes(990, 1100).map(|p| p.to_string()).collect();
+    println!("segmented_primes(990, 1100): {}", listed.join(" "));
+    let listed: Vec<String> = segmented_primes(100_000_000_000, 100_000_000_200)
+        .map(|p| p.to_string())
+        .collect();
+    println!("segmented_primes(10^11, 10^11 + 200): {}", listed.join(" "));
+    let listed: Vec<String> = primes_up_to(50).map(|p| p.to_string()).collect();
+    println!("primes_up_to(50): {}", listed.join(" "));
+    println!(
+        "prime_pi(1) = {}, prime_pi(2) = {}, prime_pi(1000000) = {}",
+        prime_pi(1),
+        prime_pi(2),
+        prime_pi(1_000_000)
+    );
+    println!(
+        "nth_prime(1) = {}, nth_prime(1000) = {}",
+        nth_prime(1),
+        nth_prime(1000)
+    );
+    let listed: Vec<String> = primes().take(10).map(|p| p.to_string()).collect();
+    println!("primes().take(10): {}", listed.join(" "));
+    println!(
+        "primes().nth(999) == nth_prime(1000): {}",
+        primes().nth(999) == Some(nth_prime(1000))
+    );
+    let listed: Vec<String> = primes_from(1 << 40)
+        .take(3)
+        .map(|p| p.to_string())
+        .collect();
+    println!("primes_from(2^40).take(3): {}", listed.join(" "));
+    let factors = SmallestFactors::new(1_000_000);
+    let listed: Vec<String> = factors
+        .factorize(720_720)
+        .iter()
+        .map(|&(p, exponent)| format!("{}^{}", p, exponent))
+        .collect();
+    println!("factorize(720720) = {}", listed.join(" * "));
+    let listed: Vec<String> = factors
+        .divisors(360)
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    println!("divisors(360): {}", listed.join(" "));
+    println!(
+        "tau(720720) = {}, sigma(720720) = {}, euler_phi(720720) = {}",
+        factors.tau(720_720),
+        factors.sigma(720_720),
+        factors.euler_phi(720_720)
+    );
+    println!(
+        "is_prime(999983) = {}, smallest_factor(999999) = {}",
+        factors.is_prime(999_983),
+        factors.smallest_factor(999_999)
+    );
+    for &(name, f) in &[
+        ("triangular", triangular as fn(u64) -> u64),
+        ("oblong", oblong),
+        ("pentagonal", pentagonal),
+    ] {
+        let listed: Vec<String> = figurate_numbers(f)
+            .take(10)
+            .map(|m| m.to_string())
+            .collect();
+        println!("{}: {}", name, listed.join(" "));
+    }
+    println!(
+        "is_triangular(5050) = {}, is_oblong(5050) = {}, is_pentagonal(5050) = {}",
+        is_triangular(&5050),
+        is_oblong(&5050),
+        is_pentagonal(&5050)
+    );
+    println!(
+        "perfect_numbers_up_to(10000): {:?}",
+        perfect_numbers_up_to(10_000)
+    );
+    println!(
+        "amicable_pairs_up_to(10000): {:?}",
+        amicable_pairs_up_to(10_000)
+    );
+    let listed: Vec<String> = mersenne_exponents().map(|p| p.to_string()).collect();
+    println!("mersenne_exponents(): {}", listed.join(" "));
+    let listed: Vec<String> = euclid_perfect_numbers()
+        .take(6)
+        .map(|m| m.to_string())
+        .collect();
+    println!("euclid_perfect_numbers().take(6): {}", listed.join(" "));
     println!("gcm(15, 9) = {}", gcm(15, 9));
+    println!("gcm(2.5, 0.75) = {}", gcm(2.5, 0.75));
 }
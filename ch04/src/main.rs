@@ -19,8 +19,11 @@
 // main.rs -- For testing functions from Chapter 4 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch04;
-use ch04::*;
+extern crate fm2gp;
+use fm2gp::ch04::*;
+use fm2gp::continued_fraction::*;
+use fm2gp::fixed_point::*;
+use fm2gp::ratio::*;
 
 fn main() {
     println!("gcm0(121, 66) = {}", gcm0(121, 66));
@@ -35,6 +38,108 @@ fn main() {
         "remainder_fibonacci(100, 7) = {}",
         remainder_fibonacci(100, 7)
     );
+    println!(
+        "quotient_fibonacci(100, 7) = {}",
+        quotient_fibonacci(100, 7)
+    );
+    let p = quotient_remainder_fibonacci(100, 7);
+    println!(
+        "quotient_remainder_fibonacci(100, 7) = pair<{}, {}>",
+        p.0, p.1
+    );
+    println!(
+        "quotient_remainder_fibonacci == quotient_remainder on all u8 pairs: {}",
+        (0..=u8::MAX).all(|a| (1..=u8::MAX)
+            .all(|b| quotient_remainder_fibonacci(a, b) == quotient_remainder(a, b)))
+    );
     println!("gcm_remainder(121, 66) = {}", gcm_remainder(121, 66));
     println!("gcd(121, 66) = {}", gcd(121, 66));
+    let terms = continued_fraction(415, 93);
+    println!("continued_fraction(415, 93) = {:?}", terms);
+    let listed: Vec<String> = convergents(&terms).iter().map(|c| c.to_string()).collect();
+    println!("convergents(415 / 93): {}", listed.join(" "));
+    println!(
+        "continued_fraction(22/7, 1) = {:?}",
+        continued_fraction(Ratio::new(22, 7), Ratio::from_integer(1))
+    );
+    for &max_denom in &[10, 100, 1000] {
+        println!(
+            "best_approximation(3.14159265, denominator <= {}) = {}",
+            max_denom,
+            best_approximation(314_159_265u64, 100_000_000, max_denom)
+        );
+    }
+    println!("try_remainder(100, 7) = {:?}", try_remainder(100, 7));
+    println!("try_quotient(100u32, 0) = {:?}", try_quotient(100u32, 0));
+    println!(
+        "try_quotient_remainder(i32::MAX, -1) = {:?}",
+        try_quotient_remainder(i32::MAX, -1)
+    );
+    println!(
+        "try_remainder(f64::INFINITY, 1.0) = {:?}",
+        try_remainder(f64::INFINITY, 1.0)
+    );
+    println!(
+        "try_largest_doubling(u32::MAX, 3) = {:?}",
+        try_largest_doubling(&u32::MAX, 3)
+    );
+    match try_largest_doubling(&3u32, 7) {
+        Ok(c) => println!("try_largest_doubling(3, 7) = {}", c),
+        Err(e) => println!("try_largest_doubling(3, 7): {}", e),
+    }
+    println!(
+        "quotient_remainder(250u8, 7u8) = {:?}",
+        quotient_remainder(250u8, 7u8)
+    );
+    println!(
+        "quotient_remainder(10^30, 7 * 10^12) = {:?}",
+        quotient_remainder(10u128.pow(30), 7 * 10u128.pow(12))
+    );
+    let (n, r) = quotient_remainder(Fixed::<2>::from_units(1000), Fixed::from_units(75));
+    println!("quotient_remainder(10.00, 0.75) = pair<{}, {}>", n, r);
+    let (n, r) = quotient_remainder(Ratio::new(22, 7), Ratio::new(1, 3));
+    println!("quotient_remainder(22/7, 1/3) = pair<{}, {}>", n, r);
+    println!(
+        "remainder_fibonacci(22/7, 1/3) = {}",
+        remainder_fibonacci(Ratio::new(22, 7), Ratio::new(1, 3))
+    );
+    println!(
+        "gcm_remainder(2.5, 0.75) = {}",
+        gcm_remainder(Fixed::<2>::from_units(250), Fixed::from_units(75))
+    );
+    println!(
+        "gcm(1/2, 1/3) = {}",
+        gcm(Ratio::new(1, 2), Ratio::new(1, 3))
+    );
+    println!(
+        "fast_segment_gcm(3/4, 5/6) = {}",
+        fast_segment_gcm(Ratio::new(3, 4), Ratio::new(5, 6))
+    );
+    println!(
+        "gcm(2.5, 0.75) = {}",
+        gcm(Fixed::<2>::from_units(250), Fixed::from_units(75))
+    );
+    println!("gcm0(2.5, 0.75) = {}", gcm0(2.5, 0.75));
+    // the diagonal and side of a square have no common measure
+    match gcm_bounded(2f64.sqrt(), 1.0, 40) {
+        Ok(measure) => println!("gcm_bounded(sqrt(2), 1, 40) = {}", measure),
+        Err(e) => println!("gcm_bounded(sqrt(2), 1, 40): {}", e),
+    }
+    // but their floating-point and fixed-point approximations have one
+    match gcm_bounded(2f64.sqrt(), 1.0, 1_000_000) {
+        Ok(measure) => println!("gcm_bounded(sqrt(2), 1) = {}", measure),
+        Err(e) => println!("gcm_bounded(sqrt(2), 1): {}", e),
+    }
+    match gcm_bounded(
+        Fixed::<9>::from_units(1_414_213_562),
+        Fixed::from_integer(1),
+        1_000_000,
+    ) {
+        Ok(measure) => println!("gcm_bounded(1.414213562, 1) = {}", measure),
+        Err(e) => println!("gcm_bounded(1.414213562, 1): {}", e),
+    }
+    println!(
+        "gcm_bounded(121, 66) = {:?}",
+        gcm_bounded(121, 66, 1_000_000)
+    );
 }
@@ -19,8 +19,10 @@
 // main.rs -- For testing functions from Chapter 7 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch07;
-use ch07::*;
+extern crate fm2gp;
+use fm2gp::addition_chain::*;
+use fm2gp::ch07::*;
+use fm2gp::ratio::*;
 
 fn main() {
     println!("mult_acc4(0, 7, 8) = {}", mult_acc4(0, 7, 8));
@@ -47,6 +49,14 @@ fn main() {
     println!("power_monoid(2, 10) = {}", 2.power_monoid(10));
     println!("power_monoid(2, 0) = {}", 2.power_monoid(0));
     println!("power_group(2., -10) = {}", (2.).power_group(-10));
+    println!(
+        "power_group(2/3, -3) = {}",
+        Ratio::new(2i64, 3).power_group(-3)
+    );
+    println!(
+        "power_monoid(-1/2, 5) = {}",
+        Ratio::new(-1i64, 2).power_monoid(5)
+    );
     let plus_int = Plus();
     println!(
         "power_accumulate_semigroup(0, 7, 8, plus_int) = {}",
@@ -64,6 +74,36 @@ fn main() {
         "power_group(7, -8, plus_int) = {}",
         plus_int.power_group(7, -8)
     );
+    println!(
+        "power_group(2., -10, multiplies) = {}",
+        _Multiplies().power_group(2., -10)
+    );
     println!("fib0(5) = {}", fib0(5));
     println!("fibonacci_iterative(5) = {}", fibonacci_iterative(5));
+    println!("binary_chain(15) = {}", binary_chain(15));
+    println!("factor_chain(15) = {}", factor_chain(15));
+    println!("window_chain(15, 2) = {}", window_chain(15, 2));
+    let chain = optimal_chain(15);
+    println!("optimal_chain(15) = {}", chain);
+    println!("optimal_chain(15).multiply(8) = {}", chain.multiply(8));
+    println!("optimal_chain(15).power(2) = {}", chain.power(2));
+    println!(
+        "short_chain(1000).apply(7, plus_int) = {}",
+        short_chain(1000).apply(7, &plus_int)
+    );
+    println!(
+        "short_chain(1000000007) = {} additions",
+        short_chain(1_000_000_007).len()
+    );
+    for &n in &[15, 23, 127, 1000, 65_535, 1_000_000_007] {
+        println!("{}", chain_report(n));
+    }
+    println!("fibonacci(46) = {}", fibonacci::<i32, _>(46));
+    println!("fibonacci(93) = {}", fibonacci::<u64, _>(93));
+    println!("fibonacci(186) = {}", fibonacci::<u128, _>(186));
+    // the last 64 bits of F(10^6)
+    println!(
+        "fibonacci(1000000) mod 2^64 = {}",
+        fibonacci::<std::num::Wrapping<u64>, _>(1000000).0
+    );
 }
@@ -19,8 +19,8 @@
 // main.rs -- For testing functions from Chapter 8 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch08;
-use ch08::*;
+extern crate fm2gp;
+use fm2gp::ch08::*;
 
 fn main() {
     let poly = [1., 2., 1.];
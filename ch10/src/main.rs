@@ -19,8 +19,8 @@
 // main.rs -- For testing functions from Chapter 10 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch10;
-use ch10::*;
+extern crate fm2gp;
+use fm2gp::ch10::*;
 
 fn equal_3(x: &&i32) -> bool {
     **x == 3
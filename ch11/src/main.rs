@@ -19,8 +19,8 @@
 // main.rs -- For testing functions from Chapter 11 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch11;
-use ch11::*;
+extern crate fm2gp;
+use fm2gp::ch11::*;
 
 fn print_range<I>(f: I)
 where
@@ -40,37 +40,37 @@ fn main() {
     println!("Setting x = begin(v); y = end(v)");
     {
         let (left, right) = v.split_at_mut(3);
-        fmgp::swap_ranges(left.iter_mut(), right[1..].iter_mut());
+        let _ = fmgp::swap_ranges(left.iter_mut(), right[1..].iter_mut());
     }
     print!("After swap_ranges(x, x+3, x+4):\t\t\t\t");
     print_range(&v);
     {
         let (left, right) = v.split_at_mut(3);
-        fmgp::swap_ranges(left.iter_mut(), right[1..].iter_mut());
+        let _ = fmgp::swap_ranges(left.iter_mut(), right[1..].iter_mut());
     }
     print!("After swap_ranges(x, x+3, x+4):\t\t\t\t");
     print_range(&v);
     {
         let (left, right) = v.split_at_mut(4);
-        fmgp::swap_ranges_bounded(left.iter_mut(), right.iter_mut());
+        let _ = fmgp::swap_ranges_bounded(left.iter_mut(), right.iter_mut());
     }
     print!("After swap_ranges(x, x+4, x+4, y):\t\t\t");
     print_range(&v);
     {
         let (left, right) = v.split_at_mut(4);
-        fmgp::swap_ranges_bounded(left.iter_mut(), right.iter_mut());
+        let _ = fmgp::swap_ranges_bounded(left.iter_mut(), right.iter_mut());
     }
     print!("After swap_ranges(x, x+4, x+4, y):\t\t\t");
     print_range(&v);
     {
         let (left, right) = v.split_at_mut(4);
-        fmgp::swap_ranges_n(left.iter_mut(), right.iter_mut(), 3);
+        let _ = fmgp::swap_ranges_n(left.iter_mut(), right.iter_mut(), 3);
     }
     print!("After swap_ranges(x, x+4, 3):\t\t\t\t");
     print_range(&v);
     {
         let (left, right) = v.split_at_mut(4);
-        fmgp::swap_ranges_n(left.iter_mut(), right.iter_mut(), 3);
+        let _ = fmgp::swap_ranges_n(left.iter_mut(), right.iter_mut(), 3);
     }
     print!("After swap_ranges(x, x+4, 3):\t\t\t\t");
     print_range(&v);
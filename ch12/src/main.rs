@@ -19,12 +19,45 @@
 // main.rs -- For testing functions from Chapter 12 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch12;
-use ch12::*;
+extern crate fm2gp;
+use fm2gp::ch12::*;
+use fm2gp::continued_fraction::*;
+use fm2gp::multiple_gcd::*;
 
 fn main() {
     println!("stein_gcd(121, 66) = {}", stein_gcd(121, 66));
     let x = extended_gcd(121, 66);
     let y = (x.0 - 121 * x.1) / 66;
     println!("extended_gcd(121, 66): gcd = {}", x.1 * 121 + y * 66);
+    // the continued fraction of a / b gives the same coefficient
+    let b = bezout_coefficient(121, 66);
+    println!("bezout_coefficient(121, 66) = ({}, {})", b.0, b.1);
+    let agree = (1..200i64).all(|a| {
+        (1..200i64).all(|b| {
+            let (x, gcd) = extended_gcd(a, b);
+            let (y, d) = bezout_coefficient(a, b);
+            gcd == d && (x - y) % (b / d) == 0
+        })
+    });
+    println!(
+        "extended_gcd agrees with the continued fractions below 200: {}",
+        agree
+    );
+    println!(
+        "gcd_all([84, -126, 210, 35]) = {}",
+        gcd_all(vec![84, -126, 210, 35])
+    );
+    println!(
+        "fold_gcd([84, 126, 210], stein_gcd) = {}",
+        fold_gcd(vec![84i64, 126, 210], stein_gcd)
+    );
+    println!("lcm_all(1..=20) = {:?}", lcm_all(1..=20u64));
+    println!("lcm_all(1..=50u64) = {:?}", lcm_all(1..=50u64));
+    let values = [84i64, 126, 210, 35];
+    let (x, gcd) = extended_gcd_all(values.iter().cloned());
+    let sum: i64 = x.iter().zip(values.iter()).map(|(x, v)| x * v).sum();
+    println!(
+        "extended_gcd_all({:?}) = ({:?}, {}), sum = {}",
+        values, x, gcd, sum
+    );
 }
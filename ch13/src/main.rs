@@ -19,10 +19,12 @@
 // main.rs -- For testing functions from Chapter 13 of fM2GP.
 // -------------------------------------------------------------------
 
-mod ch07;
-mod ch12;
-mod ch13;
-use ch13::*;
+extern crate fm2gp;
+use fm2gp::ch02::{multiply0, multiply4};
+use fm2gp::ch07::*;
+use fm2gp::ch12::*;
+use fm2gp::ch13::*;
+use fm2gp::counted::*;
 
 fn main() {
     println!("is_prime(101) = {}", is_prime(&101));
@@ -76,4 +78,35 @@ fn main() {
             0
         }
     );
+
+    reset_operation_counts();
+    is_prime(&Counted(10_007));
+    println!(
+        "is_prime(10007): {} ({} in all)",
+        operation_counts(),
+        operation_counts().total()
+    );
+    let (_, counts) =
+        count_operations(|| multiplicative_inverse_fermat(Counted(24), Counted(10_007)));
+    println!("multiplicative_inverse_fermat(24, 10007): {}", counts);
+    let (_, counts) = count_operations(|| multiplicative_inverse(Counted(24), Counted(10_007)));
+    println!("multiplicative_inverse(24, 10007): {}", counts);
+    let (_, counts) = count_operations(|| stein_gcd(Counted(121), Counted(66)));
+    println!("stein_gcd(121, 66): {}", counts);
+    let (_, counts) = count_operations(|| extended_gcd(Counted(121), Counted(66)));
+    println!("extended_gcd(121, 66): {}", counts);
+    let (_, counts) = count_operations(|| Counted(8).multiply_semigroup(Counted(15)));
+    println!("multiply_semigroup(15, 8): {}", counts);
+    let (_, counts) = count_operations(|| Counted(2).power_semigroup(Counted(15)));
+    println!(
+        "power_semigroup(2, 15): {} multiplications",
+        counts.multiplications
+    );
+    // the work on n as well as the additions on a
+    let (_, counts) = count_operations(|| multiply0(Counted(41), Counted(59)));
+    println!("multiply0(41, 59): {}", counts);
+    let (_, counts) = count_operations(|| multiply4(Counted(41), Counted(59)));
+    println!("multiply4(41, 59): {}", counts);
+    let (_, counts) = count_operations(|| multiply4(Counted(1u128 << 100), Counted(3u128)));
+    println!("multiply4(2^100, 3u128): {}", counts);
 }
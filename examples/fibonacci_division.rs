@@ -0,0 +1,33 @@
+// -------------------------------------------------------------------
+// fibonacci_division.rs -- Checks the Fibonacci division of Chapter 4
+// of fM2GP against the doubling one over every pair of u16 segments.
+//
+//     cargo run --release --example fibonacci_division
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+
+use fm2gp::ch04::*;
+
+fn main() {
+    let mut pairs = 0u64;
+    for a in 0..=u16::MAX {
+        for b in 1..=u16::MAX {
+            let expected = quotient_remainder(a, b);
+            assert_eq!(
+                quotient_remainder_fibonacci(a, b),
+                expected,
+                "quotient_remainder_fibonacci({}, {})",
+                a,
+                b
+            );
+            assert_eq!(quotient_fibonacci(a, b), expected.0);
+            assert_eq!(remainder_fibonacci(a, b), expected.1);
+            pairs += 1;
+        }
+    }
+    println!(
+        "quotient_remainder_fibonacci == quotient_remainder on all {} u16 pairs",
+        pairs
+    );
+}
@@ -0,0 +1,110 @@
+// -------------------------------------------------------------------
+// sieve_benchmark.rs -- Times the sieves of Chapter 3 of fM2GP on each
+// kind of storage, next to the wheel sieves that also leave the
+// multiples of 3, 5 (and 7) out of the table.
+//
+//     cargo run --release --example sieve_benchmark [ODD_CANDIDATES]
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+
+use fm2gp::ch03::*;
+use fm2gp::segmented_sieve::*;
+use fm2gp::wheel_sieve::*;
+use std::time::{Duration, Instant};
+
+fn sieve<S>(name: &str) -> fn(&mut S)
+where
+    S: SieveStorage + ?Sized,
+{
+    match name {
+        "sift0" => sift0,
+        "sift1" => sift1,
+        "sift30" => sift30,
+        "sift210" => sift210,
+        _ => sift,
+    }
+}
+
+// Best of a few runs, to smooth out noise
+fn time<F>(mut f: F) -> Duration
+where
+    F: FnMut(),
+{
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(name: &str, storage: &str, bytes: usize, elapsed: Duration) {
+    println!(
+        "{:<16} {:<10} {:>12} {:>10.1}",
+        name,
+        storage,
+        bytes,
+        elapsed.as_secs_f64() * 1000.0
+    );
+}
+
+fn main() {
+    let n = std::env::args()
+        .nth(1)
+        .map(|arg| arg.parse().expect("ODD_CANDIDATES must be a number"))
+        .unwrap_or(10_000_000);
+    println!("sieving {} odd candidates (primes up to {})", n, 2 * n + 1);
+    println!(
+        "{:<16} {:<10} {:>12} {:>10}",
+        "sieve", "storage", "bytes", "ms"
+    );
+
+    let mut bools = vec![false; n];
+    let mut bits = BitVector::new(n, false);
+    for &name in &["sift0", "sift1", "sift"] {
+        let (on_bools, on_bits) = (sieve::<[bool]>(name), sieve::<BitVector>(name));
+        report(name, "[bool]", bools.len(), time(|| on_bools(&mut bools)));
+        report(name, "BitVector", bits.bytes(), time(|| on_bits(&mut bits)));
+    }
+    assert!(bools.iter().cloned().eq(bits.iter()));
+    let count = bits.count_ones();
+
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let name = format!("sift_parallel({})", threads);
+    let mut parallel_bools = vec![false; n];
+    let mut parallel_bits = BitVector::new(n, false);
+    report(
+        &name,
+        "[bool]",
+        bools.len(),
+        time(|| sift_parallel(&mut parallel_bools, threads)),
+    );
+    report(
+        &name,
+        "BitVector",
+        bits.bytes(),
+        time(|| sift_parallel(&mut parallel_bits, threads)),
+    );
+    assert!(parallel_bools == bools && parallel_bits == bits);
+
+    // the wheel tables cover the same numbers with fewer entries
+    for &(name, ref wheel) in &[("sift30", wheel30()), ("sift210", wheel210())] {
+        let len = wheel.table_len(2 * n as u64 + 1);
+        let mut bools = vec![false; len];
+        let mut bits = BitVector::new(len, false);
+        let (on_bools, on_bits) = (sieve::<[bool]>(name), sieve::<BitVector>(name));
+        report(name, "[bool]", bools.len(), time(|| on_bools(&mut bools)));
+        report(name, "BitVector", bits.bytes(), time(|| on_bits(&mut bits)));
+        assert!(bools.iter().cloned().eq(bits.iter()));
+        // the wheel leaves out 3, 5 (and 7) as well as 2
+        assert_eq!(
+            bits.count_ones() + wheel.primes().len() - 1,
+            count,
+            "{} disagrees with sift",
+            name
+        );
+    }
+}
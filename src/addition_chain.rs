@@ -0,0 +1,320 @@
+// -------------------------------------------------------------------
+// addition_chain.rs -- Addition chains for constant multiplication and
+// exponentiation, generalizing multiply_by_15 from Chapter 2 of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch07::*;
+
+// An addition chain for n is a sequence a0 = 1, a1, ..., ar = n in
+// which every element after the first is the sum of two earlier ones.
+// Step k records the indices (i, j) such that a(k + 1) = ai + aj, so
+// the number of additions performed is the number of steps.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdditionChain {
+    steps: Vec<(usize, usize)>,
+}
+
+impl AdditionChain {
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    // only the chain for 1 is empty
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn values(&self) -> Vec<u64> {
+        let mut values = vec![1];
+        for &(i, j) in &self.steps {
+            let v = values[i] + values[j];
+            values.push(v);
+        }
+        values
+    }
+
+    fn push(&mut self, i: usize, j: usize) -> usize {
+        self.steps.push((i, j));
+        self.steps.len()
+    }
+
+    fn last(&self) -> usize {
+        self.steps.len()
+    }
+
+    // Appends other, scaled by the current target: other's element 0
+    // becomes our last element
+    fn compose(mut self, other: &AdditionChain) -> AdditionChain {
+        let offset = self.last();
+        for &(i, j) in &other.steps {
+            self.steps.push((offset + i, offset + j));
+        }
+        self
+    }
+
+    pub fn apply<A, Op>(&self, a: A, op: &Op) -> A
+    where
+        Op: SemigroupOperation<A>,
+    {
+        let mut values = Vec::with_capacity(self.steps.len() + 1);
+        values.push(a);
+        for &(i, j) in &self.steps {
+            let v = op.call(&values[i], &values[j]);
+            values.push(v);
+        }
+        values.pop().unwrap()
+    }
+
+    pub fn multiply<A>(&self, a: A) -> A
+    where
+        A: NoncommutativeAdditiveSemigroup,
+        for<'a, 'b> &'a A: std::ops::Add<&'b A, Output = A>,
+    {
+        self.apply(a, &Plus())
+    }
+
+    pub fn power<A>(&self, a: A) -> A
+    where
+        A: MultiplicativeSemigroup,
+        for<'a, 'b> &'a A: std::ops::Mul<&'b A, Output = A>,
+    {
+        self.apply(a, &_Multiplies())
+    }
+}
+
+impl std::fmt::Display for AdditionChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let values = self.values();
+        write!(f, "{}", values[0])?;
+        for v in &values[1..] {
+            write!(f, " {}", v)?;
+        }
+        Ok(())
+    }
+}
+
+fn empty_chain() -> AdditionChain {
+    AdditionChain { steps: Vec::new() }
+}
+
+// The chain traced by multiply4: double for every bit, add for every
+// one bit after the leading one.
+pub fn binary_chain(n: u64) -> AdditionChain {
+    // precondition(n > 0);
+    let mut chain = empty_chain();
+    let mut bit = 63 - n.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        let last = chain.last();
+        let doubled = chain.push(last, last);
+        if n >> bit & 1 == 1 {
+            chain.push(doubled, 0);
+        }
+    }
+    chain
+}
+
+fn smallest_divisor(n: u64) -> u64 {
+    // precondition: n > 1
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+    let mut i = 3;
+    while i <= n / i {
+        if n.is_multiple_of(i) {
+            return i;
+        }
+        i += 2;
+    }
+    n
+}
+
+// Knuth's factor method: chain(p) followed by chain(n / p) for the
+// smallest prime factor p, and chain(n - 1) + 1 for prime n.
+pub fn factor_chain(n: u64) -> AdditionChain {
+    // precondition(n > 0);
+    if n == 1 {
+        return empty_chain();
+    }
+    let p = smallest_divisor(n);
+    if p == n {
+        let mut chain = factor_chain(n - 1);
+        let last = chain.last();
+        chain.push(last, 0);
+        return chain;
+    }
+    factor_chain(p).compose(&factor_chain(n / p))
+}
+
+// Sliding-window method: split n from the top into windows of at most
+// width bits that end in a one bit, precompute the odd multiples 1, 3,
+// 5, ... up to the largest window, then double and add window by window.
+pub fn window_chain(n: u64, width: u32) -> AdditionChain {
+    // precondition(n > 0 && 0 < width && width < 64);
+    // each window is (bits consumed, odd value of its bits)
+    let mut windows = Vec::new();
+    let mut hi = 64 - n.leading_zeros();
+    let mut zeros = 0;
+    while hi > 0 {
+        if n >> (hi - 1) & 1 == 0 {
+            zeros += 1;
+            hi -= 1;
+            continue;
+        }
+        let mut lo = hi.saturating_sub(width);
+        while n >> lo & 1 == 0 {
+            lo += 1;
+        }
+        windows.push((zeros + hi - lo, (n >> lo) & ((1 << (hi - lo)) - 1)));
+        zeros = 0;
+        hi = lo;
+    }
+
+    let mut chain = empty_chain();
+    // odd[k] is the index of 2k + 1
+    let mut odd = vec![0];
+    let largest = windows.iter().map(|w| w.1).max().unwrap();
+    if largest > 1 {
+        let two = chain.push(0, 0);
+        while (odd.len() as u64) <= largest >> 1 {
+            let previous = *odd.last().unwrap();
+            odd.push(chain.push(previous, two));
+        }
+    }
+
+    let mut acc = odd[(windows[0].1 >> 1) as usize];
+    for &(consumed, value) in &windows[1..] {
+        for _ in 0..consumed {
+            acc = chain.push(acc, acc);
+        }
+        acc = chain.push(acc, odd[(value >> 1) as usize]);
+    }
+    for _ in 0..zeros {
+        acc = chain.push(acc, acc);
+    }
+    chain
+}
+
+// Depth-first search for a star chain of the given length, in which every
+// step adds an earlier element to the last one.
+fn search(values: &mut Vec<u64>, steps: &mut Vec<(usize, usize)>, n: u64, length: usize) -> bool {
+    let last = *values.last().unwrap();
+    if last == n {
+        return true;
+    }
+    if steps.len() == length || (last as u128) << (length - steps.len()) < n as u128 {
+        return false;
+    }
+    let i = values.len() - 1;
+    if steps.len() + 1 == length {
+        // the last step must reach n itself
+        if let Ok(j) = values.binary_search(&(n - last)) {
+            values.push(n);
+            steps.push((i, j));
+            return true;
+        }
+        return false;
+    }
+    // try the largest sums first
+    for j in (0..i + 1).rev() {
+        let v = last + values[j];
+        if v > n {
+            continue;
+        }
+        values.push(v);
+        steps.push((i, j));
+        if search(values, steps, n, length) {
+            return true;
+        }
+        values.pop();
+        steps.pop();
+    }
+    false
+}
+
+// Shortest star chain by iterative deepening. Star chains are optimal
+// for every n below 12509, the first n that has no optimal star chain.
+// The search is exponential in the length of the chain, so it is meant
+// for small n only.
+pub fn optimal_chain(n: u64) -> AdditionChain {
+    // precondition(n > 0);
+    let mut length = (63 - n.leading_zeros()) as usize;
+    loop {
+        let mut values = vec![1];
+        let mut steps = Vec::new();
+        if search(&mut values, &mut steps, n, length) {
+            return AdditionChain { steps };
+        }
+        length += 1;
+    }
+}
+
+pub const OPTIMAL_CHAIN_LIMIT: u64 = 1 << 10;
+
+fn best_window_chain(n: u64) -> AdditionChain {
+    (1..7)
+        .map(|width| window_chain(n, width))
+        .min_by_key(AdditionChain::len)
+        .unwrap()
+}
+
+// The shortest of the heuristic chains, or the optimal chain when n does
+// not exceed OPTIMAL_CHAIN_LIMIT.
+pub fn short_chain(n: u64) -> AdditionChain {
+    // precondition(n > 0);
+    if n <= OPTIMAL_CHAIN_LIMIT {
+        return optimal_chain(n);
+    }
+    let chains = [binary_chain(n), factor_chain(n), best_window_chain(n)];
+    chains.iter().min_by_key(|c| c.len()).unwrap().clone()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainReport {
+    pub n: u64,
+    pub binary: usize,
+    pub factor: usize,
+    pub window: usize,
+    pub optimal: Option<usize>,
+}
+
+pub fn chain_report(n: u64) -> ChainReport {
+    // precondition(n > 0);
+    ChainReport {
+        n,
+        binary: binary_chain(n).len(),
+        factor: factor_chain(n).len(),
+        window: best_window_chain(n).len(),
+        optimal: if n <= OPTIMAL_CHAIN_LIMIT {
+            Some(optimal_chain(n).len())
+        } else {
+            None
+        },
+    }
+}
+
+impl std::fmt::Display for ChainReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "n = {}: binary {}, factor {} ({:+}), window {} ({:+})",
+            self.n,
+            self.binary,
+            self.factor,
+            self.factor as i64 - self.binary as i64,
+            self.window,
+            self.window as i64 - self.binary as i64
+        )?;
+        if let Some(optimal) = self.optimal {
+            write!(
+                f,
+                ", optimal {} ({:+})",
+                optimal,
+                optimal as i64 - self.binary as i64
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,593 @@
+// Copyright (c) 2014 Alexander A. Stepanov and Daniel E. Rose
+//
+// Permission to use, copy, modify, distribute and sell this software
+// and its documentation for any purpose is hereby granted without
+// fee, provided that the above copyright notice appear in all copies
+// and that both that copyright notice and this permission notice
+// appear in supporting documentation. The authors make no
+// representations about the suitability of this software for any
+// purpose. It is provided "as is" without express or implied
+// warranty.
+//
+// This code accompanies the "fM2GP" book:
+//
+//	From Mathematics to Generic Programming
+//	by Alexander Stepanov and Daniel E. Rose
+//	Addison-Wesley Professional, 2015
+//
+// -------------------------------------------------------------------
+// ch02.rs -- Functions from Chapter 2 of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate num_traits;
+use ch04::{largest_doubling, HalvableSegment};
+
+// Section 2.1
+
+// As in Chapter 7, any type with the operations below is an Integer,
+// whether or not it is a primitive.
+pub trait Integer
+where
+    Self: Clone,
+    Self: PartialOrd,
+    Self: num_traits::Zero,
+    Self: num_traits::One,
+    Self: std::ops::Sub<Output = Self>,
+{
+    fn odd(&self) -> bool;
+    fn half(self) -> Self;
+}
+
+impl<T> Integer for T
+where
+    T: Clone,
+    T: PartialOrd,
+    T: num_traits::Zero,
+    T: num_traits::One,
+    T: std::ops::Sub<Output = T>,
+    T: std::ops::Shr<T, Output = T>,
+    for<'a> &'a T: std::ops::BitAnd<T, Output = T>,
+{
+    fn odd(&self) -> bool {
+        self & T::one() == T::one()
+    }
+
+    fn half(self) -> T {
+        self >> T::one()
+    }
+}
+
+fn odd<N>(n: &N) -> bool
+where
+    N: Integer,
+{
+    n.odd()
+}
+
+fn half<N>(n: N) -> N
+where
+    N: Integer,
+{
+    n.half()
+}
+
+pub fn multiply0<A, N>(n: N, a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    N: Integer,
+{
+    if n == N::one() {
+        return a;
+    }
+    multiply0(n - N::one(), a.clone()) + a
+}
+
+pub fn multiply1<A, N>(n: N, a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    if n == N::one() {
+        return a;
+    }
+    let n_odd = odd(&n);
+    let mut result = multiply1(half(n), a.clone() + a.clone());
+    if n_odd {
+        result += a
+    }
+    result
+}
+
+pub fn multiply_by_15<A>(a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+{
+    let b = (a.clone() + a.clone()) + a;
+    let c = b.clone() + b.clone();
+    (c.clone() + c) + b
+}
+
+// Section 2.2
+
+pub fn mult_acc0<A, N>(r: A, n: N, a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    N: Integer,
+{
+    if n == N::one() {
+        return r + a;
+    }
+    if odd(&n) {
+        mult_acc0(r + a.clone(), half(n), a.clone() + a)
+    } else {
+        mult_acc0(r, half(n), a.clone() + a)
+    }
+}
+
+pub fn mult_acc1<A, N>(mut r: A, n: N, a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    if n == N::one() {
+        return r + a;
+    }
+    if odd(&n) {
+        r += a.clone()
+    }
+    mult_acc1(r, half(n), a.clone() + a)
+}
+
+pub fn mult_acc2<A, N>(mut r: A, n: N, a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    if odd(&n) {
+        r += a.clone();
+        if n == N::one() {
+            return r;
+        }
+    }
+    mult_acc2(r, half(n), a.clone() + a)
+}
+
+pub fn mult_acc3<A, N>(mut r: A, mut n: N, mut a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    if odd(&n) {
+        r += a.clone();
+        if n == N::one() {
+            return r;
+        }
+    }
+    n = half(n);
+    a += a.clone();
+    mult_acc3(r, n, a)
+}
+
+pub fn mult_acc4<A, N>(mut r: A, mut n: N, mut a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    loop {
+        if odd(&n) {
+            r += a.clone();
+            if n == N::one() {
+                return r;
+            }
+        }
+        n = half(n);
+        a += a.clone();
+    }
+}
+
+pub fn multiply2<A, N>(n: N, a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    if n == N::one() {
+        return a;
+    }
+    mult_acc4(a.clone(), n - N::one(), a)
+}
+
+pub fn multiply3<A, N>(mut n: N, mut a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    while !odd(&n) {
+        a += a.clone();
+        n = half(n);
+    }
+    if n == N::one() {
+        return a;
+    }
+    mult_acc4(a.clone(), n - N::one(), a)
+}
+
+pub fn multiply4<A, N>(mut n: N, mut a: A) -> A
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    while !odd(&n) {
+        a += a.clone();
+        n = half(n);
+    }
+    if n == N::one() {
+        return a;
+    }
+    // even(n - N::one()) => n - N::one() != 1
+    mult_acc4(a.clone(), half(n - N::one()), a.clone() + a)
+}
+
+// Section 2.2, overflow-aware variants
+//
+// These accept any n, including zero and negative n, with the group
+// semantics of multiply_group in Chapter 7: n * a == (-n) * (-a). The
+// mult_acc variants give r + n * a whenever it is in range, even when
+// n * a alone is not.
+
+pub trait Number
+where
+    Self: Clone + PartialOrd,
+{
+    fn zero() -> Self;
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+}
+
+macro_rules! number_impl {
+    ($($t:ty)*) => ($(
+        impl Number for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn min_value() -> Self {
+                <$t>::MIN
+            }
+
+            fn max_value() -> Self {
+                <$t>::MAX
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn wrapping_add(self, other: Self) -> Self {
+                <$t>::wrapping_add(self, other)
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$t>::wrapping_sub(self, other)
+            }
+        }
+    )*)
+}
+
+number_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+// r + n * a for any n, by mult_acc4 with add and sub, which fail on
+// overflow. A negative n subtracts a, -(n + 1) + 1 times, so neither n
+// nor a is ever negated. The partial sums run from r to the result by
+// terms of one sign, so they stay in range whenever the result does; so
+// do the doublings of a, save the last, which is added as two halves.
+fn mult_acc_with_op<A, N, Add, Sub>(mut r: A, n: N, a: A, add: &Add, sub: &Sub) -> Option<A>
+where
+    A: Clone,
+    N: Integer,
+    Add: Fn(A, A) -> Option<A>,
+    Sub: Fn(A, A) -> Option<A>,
+{
+    if n < N::zero() {
+        r = sub(r, a.clone())?;
+        // -(n + 1) does not overflow, even for the minimum of N
+        let n = N::zero() - (n + N::one());
+        return mult_acc_nonnegative(r, n, a, sub, add);
+    }
+    mult_acc_nonnegative(r, n, a, add, add)
+}
+
+// r + n * a with the terms accumulated by acc and doubled by double
+fn mult_acc_nonnegative<A, N, Acc, Double>(
+    mut r: A,
+    mut n: N,
+    mut a: A,
+    acc: &Acc,
+    double: &Double,
+) -> Option<A>
+where
+    A: Clone,
+    N: Integer,
+    Acc: Fn(A, A) -> Option<A>,
+    Double: Fn(A, A) -> Option<A>,
+{
+    // precondition: n >= 0
+    if n == N::zero() {
+        return Some(r);
+    }
+    loop {
+        if odd(&n) {
+            r = acc(r, a.clone())?;
+            if n == N::one() {
+                return Some(r);
+            }
+        }
+        n = half(n);
+        if n == N::one() {
+            // 2a need not be in range when r + 2a is
+            r = acc(r, a.clone())?;
+            return acc(r, a);
+        }
+        a = double(a.clone(), a)?;
+    }
+}
+
+// r + n * a, or None if it overflows; the partial products may be out of
+// range when the result is not
+pub fn checked_mult_acc4<A, N>(r: A, n: N, a: A) -> Option<A>
+where
+    A: Number,
+    N: Integer,
+{
+    mult_acc_with_op(r, n, a, &A::checked_add, &A::checked_sub)
+}
+
+pub fn checked_multiply4<A, N>(n: N, a: A) -> Option<A>
+where
+    A: Number,
+    N: Integer,
+{
+    checked_mult_acc4(A::zero(), n, a)
+}
+
+pub fn wrapping_mult_acc4<A, N>(r: A, n: N, a: A) -> A
+where
+    A: Number,
+    N: Integer,
+{
+    let add = |x: A, y: A| Some(x.wrapping_add(y));
+    let sub = |x: A, y: A| Some(x.wrapping_sub(y));
+    mult_acc_with_op(r, n, a, &add, &sub).unwrap()
+}
+
+pub fn wrapping_multiply4<A, N>(n: N, a: A) -> A
+where
+    A: Number,
+    N: Integer,
+{
+    wrapping_mult_acc4(A::zero(), n, a)
+}
+
+// r + n * a clamped to the range of A
+pub fn saturating_mult_acc4<A, N>(r: A, n: N, a: A) -> A
+where
+    A: Number,
+    N: Integer,
+{
+    // the partial sums only move towards the result, so an overflow means
+    // the result lies beyond the bound on the side of n * a
+    let positive = (n > N::zero()) == (a > A::zero());
+    checked_mult_acc4(r, n, a).unwrap_or_else(|| {
+        if positive {
+            A::max_value()
+        } else {
+            A::min_value()
+        }
+    })
+}
+
+pub fn saturating_multiply4<A, N>(n: N, a: A) -> A
+where
+    A: Number,
+    N: Integer,
+{
+    saturating_mult_acc4(A::zero(), n, a)
+}
+
+// Egyptian division, mirroring Section 2.2
+//
+// The divisor is doubled up to the largest doubling that does not exceed
+// the dividend, then halved back down, subtracting it wherever it fits;
+// each subtraction is a one bit of the quotient. Only addition,
+// subtraction, doubling and halving are used. Like quotient_remainder in
+// Chapter 4, these divide any halvable line segment, and check their
+// preconditions in debug builds.
+
+pub fn divide0<S>(a: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    // precondition: a >= 0 && b > 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "divide0 needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return (num_traits::zero(), a);
+    }
+    let (q, r) = divide0(a - b.clone(), b);
+    (q + num_traits::one(), r)
+}
+
+pub fn divide1<S>(a: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    // precondition: a >= 0 && b > 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "divide1 needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return (num_traits::zero(), a);
+    }
+    if a.clone() - b.clone() < b {
+        return (num_traits::one(), a - b);
+    }
+    // b <= a - b => b + b does not overflow
+    let (mut q, mut r) = divide1(a, b.clone() + b.clone());
+    q = q.clone() + q;
+    if b <= r {
+        r = r - b;
+        q = q + num_traits::one();
+    }
+    (q, r)
+}
+
+// divide_acc(q, a, c, b) returns (q * 2^k + a / b, a % b) where c is b
+// doubled k times and a < c + c.
+
+pub fn divide_acc0<S>(q: S::Quotient, a: S, c: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    if c == b {
+        if c <= a {
+            return (q + num_traits::one(), a - c);
+        }
+        return (q, a);
+    }
+    if c <= a {
+        let q = q + num_traits::one();
+        divide_acc0(q.clone() + q, a - c.clone(), c.half(), b)
+    } else {
+        divide_acc0(q.clone() + q, a, c.half(), b)
+    }
+}
+
+pub fn divide_acc1<S>(mut q: S::Quotient, mut a: S, c: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    if c <= a {
+        q = q + num_traits::one();
+        a = a - c.clone();
+    }
+    if c == b {
+        return (q, a);
+    }
+    divide_acc1(q.clone() + q, a, c.half(), b)
+}
+
+pub fn divide_acc2<S>(mut q: S::Quotient, mut a: S, c: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    if c <= a {
+        q = q + num_traits::one();
+        a = a - c.clone();
+        if c == b {
+            return (q, a);
+        }
+    } else if c == b {
+        return (q, a);
+    }
+    divide_acc2(q.clone() + q, a, c.half(), b)
+}
+
+pub fn divide_acc3<S>(mut q: S::Quotient, mut a: S, mut c: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    if c <= a {
+        q = q + num_traits::one();
+        a = a - c.clone();
+    }
+    if c == b {
+        return (q, a);
+    }
+    q = q.clone() + q;
+    c = c.half();
+    divide_acc3(q, a, c, b)
+}
+
+pub fn divide_acc4<S>(mut q: S::Quotient, mut a: S, mut c: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    loop {
+        if c <= a {
+            q = q + num_traits::one();
+            a = a - c.clone();
+        }
+        if c == b {
+            return (q, a);
+        }
+        q = q.clone() + q;
+        c = c.half();
+    }
+}
+
+pub fn divide2<S>(a: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    // precondition: a >= 0 && b > 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "divide2 needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return (num_traits::zero(), a);
+    }
+    let c = largest_doubling(&a, b.clone());
+    divide_acc4(num_traits::zero(), a, c, b)
+}
+
+pub fn divide3<S>(mut a: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    // precondition: a >= 0 && b > 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "divide3 needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return (num_traits::zero(), a);
+    }
+    let c = largest_doubling(&a, b.clone());
+    a = a - c.clone();
+    if c == b {
+        return (num_traits::one(), a);
+    }
+    // the leading bit of the quotient is one
+    let two = num_traits::one::<S::Quotient>() + num_traits::one();
+    divide_acc4(two, a, c.half(), b)
+}
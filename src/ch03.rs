@@ -0,0 +1,363 @@
+// Copyright (c) 2014 Alexander A. Stepanov and Daniel E. Rose
+//
+// Permission to use, copy, modify, distribute and sell this software
+// and its documentation for any purpose is hereby granted without
+// fee, provided that the above copyright notice appear in all copies
+// and that both that copyright notice and this permission notice
+// appear in supporting documentation. The authors make no
+// representations about the suitability of this software for any
+// purpose. It is provided "as is" without express or implied
+// warranty.
+//
+// This code accompanies the "fM2GP" book:
+//
+//	From Mathematics to Generic Programming
+//	by Alexander Stepanov and Daniel E. Rose
+//	Addison-Wesley Professional, 2015
+//
+// -------------------------------------------------------------------
+// ch03.rs -- Functions from Chapter 3 of fM2GP.
+// -------------------------------------------------------------------
+
+use ch04::LineSegment;
+
+type Integer = usize;
+
+// Sieve storage: a table of flags, one per odd candidate. [bool] spends
+// a byte per flag, BitVector a bit.
+
+pub trait SieveStorage {
+    fn len(&self) -> usize;
+    fn get(&self, i: usize) -> bool;
+    fn set(&mut self, i: usize, value: bool);
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn fill(&mut self, value: bool) {
+        for i in 0..self.len() {
+            self.set(i, value);
+        }
+    }
+}
+
+impl SieveStorage for [bool] {
+    fn len(&self) -> usize {
+        <[bool]>::len(self)
+    }
+
+    fn get(&self, i: usize) -> bool {
+        self[i]
+    }
+
+    fn set(&mut self, i: usize, value: bool) {
+        self[i] = value;
+    }
+
+    fn fill(&mut self, value: bool) {
+        <[bool]>::fill(self, value);
+    }
+}
+
+impl SieveStorage for Vec<bool> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, i: usize) -> bool {
+        self[i]
+    }
+
+    fn set(&mut self, i: usize, value: bool) {
+        self[i] = value;
+    }
+
+    fn fill(&mut self, value: bool) {
+        <[bool]>::fill(self, value);
+    }
+}
+
+const WORD_BITS: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitVector {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVector {
+    pub fn new(len: usize, value: bool) -> BitVector {
+        let mut bits = BitVector {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        };
+        SieveStorage::fill(&mut bits, value);
+        bits
+    }
+
+    // Number of bytes used by the flags
+    pub fn bytes(&self) -> usize {
+        self.words.len() * std::mem::size_of::<u64>()
+    }
+
+    // Number of flags that are set
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |i| self.get(i))
+    }
+}
+
+impl SieveStorage for BitVector {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> bool {
+        // the word index is bounds checked; only the bits past len but
+        // within the last word need this
+        debug_assert!(i < self.len);
+        get_bit(&self.words, i)
+    }
+
+    #[inline]
+    fn set(&mut self, i: usize, value: bool) {
+        debug_assert!(i < self.len);
+        set_bit(&mut self.words, i, value);
+    }
+
+    fn fill(&mut self, value: bool) {
+        fill_bits(&mut self.words, self.len, value);
+    }
+}
+
+// A run of consecutive flags of a BitVector, starting on a word boundary
+#[derive(Debug)]
+pub struct BitSlice<'a> {
+    words: &'a mut [u64],
+    len: usize,
+}
+
+impl SieveStorage for BitSlice<'_> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> bool {
+        debug_assert!(i < self.len);
+        get_bit(self.words, i)
+    }
+
+    #[inline]
+    fn set(&mut self, i: usize, value: bool) {
+        debug_assert!(i < self.len);
+        set_bit(self.words, i, value);
+    }
+
+    fn fill(&mut self, value: bool) {
+        fill_bits(self.words, self.len, value);
+    }
+}
+
+#[inline]
+fn get_bit(words: &[u64], i: usize) -> bool {
+    words[i / WORD_BITS] >> (i % WORD_BITS) & 1 == 1
+}
+
+#[inline]
+fn set_bit(words: &mut [u64], i: usize, value: bool) {
+    let mask = 1 << (i % WORD_BITS);
+    if value {
+        words[i / WORD_BITS] |= mask;
+    } else {
+        words[i / WORD_BITS] &= !mask;
+    }
+}
+
+fn fill_bits(words: &mut [u64], len: usize, value: bool) {
+    let word = if value { !0 } else { 0 };
+    for w in words.iter_mut() {
+        *w = word;
+    }
+    // keep the bits past the end clear
+    if !len.is_multiple_of(WORD_BITS) {
+        if let Some(last) = words.last_mut() {
+            *last &= (1 << (len % WORD_BITS)) - 1;
+        }
+    }
+}
+
+impl<S> SieveStorage for &mut S
+where
+    S: SieveStorage + ?Sized,
+{
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn get(&self, i: usize) -> bool {
+        (**self).get(i)
+    }
+
+    fn set(&mut self, i: usize, value: bool) {
+        (**self).set(i, value);
+    }
+
+    fn fill(&mut self, value: bool) {
+        (**self).fill(value);
+    }
+}
+
+// Storage that can be cut into consecutive pieces, to be sieved on
+// separate threads.
+pub trait SplitSieveStorage: SieveStorage {
+    type Piece<'a>: SieveStorage + Send
+    where
+        Self: 'a;
+
+    // Pieces of at least piece_size flags each, in order; only the last
+    // one may be shorter. BitVector rounds piece_size up to whole words.
+    fn pieces_mut(&mut self, piece_size: usize) -> Vec<Self::Piece<'_>>;
+}
+
+impl SplitSieveStorage for [bool] {
+    type Piece<'a> = &'a mut [bool];
+
+    fn pieces_mut(&mut self, piece_size: usize) -> Vec<&mut [bool]> {
+        // precondition: piece_size > 0
+        self.chunks_mut(piece_size).collect()
+    }
+}
+
+impl SplitSieveStorage for Vec<bool> {
+    type Piece<'a> = &'a mut [bool];
+
+    fn pieces_mut(&mut self, piece_size: usize) -> Vec<&mut [bool]> {
+        SplitSieveStorage::pieces_mut(&mut self[..], piece_size)
+    }
+}
+
+impl SplitSieveStorage for BitVector {
+    type Piece<'a> = BitSlice<'a>;
+
+    fn pieces_mut(&mut self, piece_size: usize) -> Vec<BitSlice<'_>> {
+        // precondition: piece_size > 0
+        let piece_words = piece_size.div_ceil(WORD_BITS);
+        let mut len = self.len;
+        self.words
+            .chunks_mut(piece_words)
+            .map(|words| {
+                let piece_len = std::cmp::min(len, piece_words * WORD_BITS);
+                len -= piece_len;
+                BitSlice {
+                    words,
+                    len: piece_len,
+                }
+            })
+            .collect()
+    }
+}
+
+// Section 3.3
+
+pub fn mark_sieve<S>(sieve: &mut S, mut first: usize, factor: Integer)
+where
+    S: SieveStorage + ?Sized,
+{
+    let last = sieve.len();
+    // assert(first != last)
+    sieve.set(first, false);
+    while last - first > factor {
+        first += factor;
+        sieve.set(first, false);
+    }
+}
+
+pub fn sift0<S>(first: &mut S)
+where
+    S: SieveStorage + ?Sized,
+{
+    let n = first.len();
+    first.fill(true);
+    let mut i = 0;
+    let mut index_square = 3;
+    while index_square < n {
+        // invariant: index_square = 2i^2 + 6i + 3
+        if first.get(i) {
+            // if current candidate is prime
+            mark_sieve(first, index_square, i + i + 3); // factor
+        }
+        i += 1;
+        index_square = 2 * i * (i + 3) + 3;
+    }
+}
+
+pub fn sift1<S>(first: &mut S)
+where
+    S: SieveStorage + ?Sized,
+{
+    let n = first.len();
+    first.fill(true);
+    let mut i = 0;
+    let mut index_square = 3;
+    let mut factor = 3;
+    while index_square < n {
+        // invariant: index_square = 2i^2 + 6i + 3, factor = 2i + 3
+        if first.get(i) {
+            mark_sieve(first, index_square, factor);
+        }
+        i += 1;
+        factor = i + i + 3;
+        index_square = 2 * i * (i + 3) + 3;
+    }
+}
+
+pub fn sift<S>(first: &mut S)
+where
+    S: SieveStorage + ?Sized,
+{
+    let n = first.len();
+    first.fill(true);
+    let mut i = 0;
+    let mut index_square = 3;
+    let mut factor = 3;
+    while index_square < n {
+        // invariant: index_square = 2i^2 + 6i + 3, factor = 2i + 3
+        if first.get(i) {
+            mark_sieve(first, index_square, factor);
+        }
+        i += 1;
+        index_square += factor;
+        factor += 2;
+        index_square += factor;
+    }
+}
+
+// Section 3.5
+
+// The subtractive gcm of the book, as a loop over any LineSegment. Each
+// subtraction must shorten the longer segment; one that does not, as
+// with a zero, negative, infinite or NaN segment, or a float too small
+// to change the other, would go on forever, so it panics instead.
+pub fn gcm<S>(mut a: S, mut b: S) -> S
+where
+    S: LineSegment,
+{
+    while a != b {
+        if b < a {
+            let r = a.clone() - b.clone();
+            assert!(r < a, "gcm: a segment is not positive and finite");
+            a = r;
+        } else {
+            let r = b.clone() - a.clone();
+            assert!(r < b, "gcm: a segment is not positive and finite");
+            b = r;
+        }
+    }
+    a
+}
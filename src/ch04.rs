@@ -0,0 +1,573 @@
+// Copyright (c) 2014 Alexander A. Stepanov and Daniel E. Rose
+//
+// Permission to use, copy, modify, distribute and sell this software
+// and its documentation for any purpose is hereby granted without
+// fee, provided that the above copyright notice appear in all copies
+// and that both that copyright notice and this permission notice
+// appear in supporting documentation. The authors make no
+// representations about the suitability of this software for any
+// purpose. It is provided "as is" without express or implied
+// warranty.
+//
+// This code accompanies the "fM2GP" book:
+//
+//	From Mathematics to Generic Programming
+//	by Alexander Stepanov and Daniel E. Rose
+//	Addison-Wesley Professional, 2015
+//
+// -------------------------------------------------------------------
+// ch04.rs -- Functions from Chapter 4 of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate num_traits;
+extern crate std;
+
+// A line segment: anything that can be compared, and added to and
+// subtracted from segments of the same kind. The measure algorithms run
+// on integers, rationals, fixed-point decimals and floats alike.
+pub trait LineSegment:
+    Clone + PartialOrd + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self>
+{
+}
+
+impl<T> LineSegment for T where
+    T: Clone + PartialOrd + std::ops::Add<Output = T> + std::ops::Sub<Output = T>
+{
+}
+
+// Section 4.2
+
+fn _odd(n: u32) -> bool {
+    n & 0x1 == 1
+}
+
+pub fn gcm0<S>(mut a: S, mut b: S) -> S
+where
+    S: LineSegment,
+{
+    while a != b {
+        if b < a {
+            a = a - b.clone()
+        } else {
+            b = b - a.clone()
+        }
+    }
+    a
+}
+
+pub fn gcm1<S>(mut a: S, mut b: S) -> S
+where
+    S: LineSegment,
+{
+    while a != b {
+        while b < a {
+            a = a - b.clone()
+        }
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+
+fn segment_remainder<S>(mut a: S, b: S) -> S
+where
+    S: LineSegment,
+{
+    while b < a {
+        a = a - b.clone()
+    }
+    a
+}
+
+pub fn gcm<S>(mut a: S, mut b: S) -> S
+where
+    S: LineSegment,
+{
+    while a != b {
+        a = segment_remainder(a, b.clone());
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+
+fn fast_segment_remainder<S>(mut a: S, b: S) -> S
+where
+    S: LineSegment,
+{
+    if a <= b {
+        return a;
+    }
+    if a.clone() - b.clone() <= b {
+        return a - b;
+    }
+    a = fast_segment_remainder(a, b.clone() + b.clone());
+    if a <= b {
+        return a;
+    }
+    a - b
+}
+
+pub fn fast_segment_gcm<S>(mut a: S, mut b: S) -> S
+where
+    S: LineSegment,
+{
+    while a != b {
+        a = fast_segment_remainder(a, b.clone());
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+
+// gcm gave up after the given number of subtractions: the segments are
+// incommensurable, or their common measure is too small to reach.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DidNotConverge {
+    pub steps: usize,
+}
+
+impl std::fmt::Display for DidNotConverge {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "no common measure found in {} steps", self.steps)
+    }
+}
+
+impl std::error::Error for DidNotConverge {}
+
+// gcm, stopping after max_steps subtractions. This is what makes the
+// incommensurability of the side and diagonal of a square safe to run.
+// Segments neither of which is shorter than the other, such as NaN,
+// cannot be measured, and give up at once.
+pub fn gcm_bounded<S>(mut a: S, mut b: S, max_steps: usize) -> Result<S, DidNotConverge>
+where
+    S: LineSegment,
+{
+    let mut steps = 0;
+    while a != b {
+        if !(a < b || b < a) {
+            return Err(DidNotConverge { steps });
+        }
+        while b < a {
+            if steps == max_steps {
+                return Err(DidNotConverge { steps });
+            }
+            a = a - b.clone();
+            steps += 1;
+        }
+        std::mem::swap(&mut a, &mut b);
+    }
+    Ok(a)
+}
+
+// Section 4.5
+
+// Line segments that can also be halved. The doubling algorithms only
+// halve segments they got by doubling, so the halving is always exact.
+// Quotient counts how many times one segment fits in another.
+pub trait HalvableSegment: LineSegment + num_traits::Zero {
+    type Quotient: Clone
+        + num_traits::Zero
+        + num_traits::One
+        + std::ops::Sub<Output = Self::Quotient>;
+
+    fn half(self) -> Self;
+}
+
+// Line segments with a quotient, which is all the Fibonacci division
+// needs: every halvable segment, and segments that cannot be halved.
+// Bound a function by one of the two traits, not both, or S::Quotient
+// is ambiguous.
+pub trait MeasurableSegment: LineSegment {
+    type Quotient: Clone
+        + num_traits::Zero
+        + num_traits::One
+        + std::ops::Sub<Output = Self::Quotient>;
+}
+
+impl<S> MeasurableSegment for S
+where
+    S: HalvableSegment,
+{
+    type Quotient = <S as HalvableSegment>::Quotient;
+}
+
+macro_rules! halvable_integer {
+    ($($t:ty)*) => ($(
+        impl HalvableSegment for $t {
+            type Quotient = $t;
+
+            fn half(self) -> $t {
+                self >> 1
+            }
+        }
+    )*)
+}
+
+// segments are never negative, so the signed types halve by shifting too
+halvable_integer! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+macro_rules! halvable_float {
+    ($($t:ty)*) => ($(
+        impl HalvableSegment for $t {
+            type Quotient = $t;
+
+            fn half(self) -> $t {
+                self / 2.0
+            }
+        }
+    )*)
+}
+
+halvable_float! { f32 f64 }
+
+pub fn _fast_segment_remainder1<S>(mut a: S, b: S) -> S
+where
+    S: LineSegment,
+{
+    // precondition: b != 0
+    if a < b {
+        return a;
+    }
+    if a.clone() - b.clone() < b {
+        return a - b;
+    }
+    a = _fast_segment_remainder1(a, b.clone() + b.clone());
+    if a < b {
+        return a;
+    }
+    a - b
+}
+
+// Doubling b while it fits in what is left of a keeps b + b <= a, so
+// the doubling itself never overflows.
+pub fn largest_doubling<S>(a: &S, mut b: S) -> S
+where
+    S: HalvableSegment,
+{
+    // precondition: b != 0 && a >= b
+    debug_assert!(
+        b > S::zero() && *a >= b,
+        "largest_doubling needs 0 < b <= a"
+    );
+    while a.clone() - b.clone() >= b {
+        b = b.clone() + b;
+    }
+    b
+}
+
+pub fn remainder<S>(mut a: S, b: S) -> S
+where
+    S: HalvableSegment,
+{
+    // precondition: b != 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "remainder needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return a;
+    }
+    let mut c = largest_doubling(&a, b.clone());
+    a = a - c.clone();
+    while c != b {
+        c = c.half();
+        if c <= a {
+            a = a - c.clone();
+        }
+    }
+    a
+}
+
+pub fn quotient<S>(mut a: S, b: S) -> S::Quotient
+where
+    S: HalvableSegment,
+{
+    // Precondition: b > 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "quotient needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return num_traits::zero();
+    }
+    let mut c = largest_doubling(&a, b.clone());
+    let mut n: S::Quotient = num_traits::one();
+    a = a - c.clone();
+    while c != b {
+        c = c.half();
+        n = n.clone() + n;
+        if c <= a {
+            a = a - c.clone();
+            n = n + num_traits::one();
+        }
+    }
+    n
+}
+
+pub fn quotient_remainder<S>(mut a: S, b: S) -> (S::Quotient, S)
+where
+    S: HalvableSegment,
+{
+    // Precondition: b > 0
+    debug_assert!(
+        b > S::zero() && a >= S::zero(),
+        "quotient_remainder needs a >= 0 and b > 0"
+    );
+    if a < b {
+        return (num_traits::zero(), a);
+    }
+    let mut c = largest_doubling(&a, b.clone());
+    let mut n: S::Quotient = num_traits::one();
+    a = a - c.clone();
+    while c != b {
+        c = c.half();
+        n = n.clone() + n;
+        if c <= a {
+            a = a - c.clone();
+            n = n + num_traits::one();
+        }
+    }
+    (n, a)
+}
+
+// What the Fibonacci walk keeps besides the remainder: nothing, or the
+// multipliers of b, which add up to the quotient
+trait FibonacciCount: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+}
+
+#[derive(Clone)]
+struct Uncounted;
+
+impl FibonacciCount for Uncounted {
+    fn zero() -> Uncounted {
+        Uncounted
+    }
+
+    fn one() -> Uncounted {
+        Uncounted
+    }
+
+    fn add(self, _: Uncounted) -> Uncounted {
+        Uncounted
+    }
+
+    fn sub(self, _: Uncounted) -> Uncounted {
+        Uncounted
+    }
+}
+
+#[derive(Clone)]
+struct Multiplier<N>(N);
+
+impl<N> FibonacciCount for Multiplier<N>
+where
+    N: Clone + num_traits::Zero + num_traits::One + std::ops::Sub<Output = N>,
+{
+    fn zero() -> Multiplier<N> {
+        Multiplier(N::zero())
+    }
+
+    fn one() -> Multiplier<N> {
+        Multiplier(N::one())
+    }
+
+    fn add(self, other: Multiplier<N>) -> Multiplier<N> {
+        Multiplier(self.0 + other.0)
+    }
+
+    fn sub(self, other: Multiplier<N>) -> Multiplier<N> {
+        Multiplier(self.0 - other.0)
+    }
+}
+
+// Walks up the Fibonacci multiples of b as far as a, then back down,
+// subtracting each one that fits. The multiples subtracted are distinct
+// and never consecutive, so the quotient is their Zeckendorf sum. Unlike
+// the book's version, no multiple larger than a is ever formed, so the
+// walk cannot overflow.
+fn fibonacci_divide<S, N>(mut a: S, mut b: S) -> (N, S)
+where
+    S: LineSegment,
+    N: FibonacciCount,
+{
+    // Precondition: b > 0
+    if a < b {
+        return (N::zero(), a);
+    }
+    // invariant: b = m * b0 and c = n * b0 are consecutive Fibonacci
+    // multiples of the original b0, with c <= a
+    let mut c = b.clone();
+    let (mut m, mut n) = (N::one(), N::one());
+    while a.clone() - c.clone() >= b {
+        let tmp = c.clone();
+        c = c + b;
+        b = tmp;
+        let tmp = n.clone();
+        n = n.add(m);
+        m = tmp;
+    }
+    let mut q = N::zero();
+    loop {
+        if a >= c {
+            a = a - c.clone();
+            q = q.add(n.clone());
+        }
+        if b >= c {
+            // c = b0
+            break;
+        }
+        let tmp = c - b.clone();
+        c = b;
+        b = tmp;
+        let tmp = n.sub(m.clone());
+        n = m;
+        m = tmp;
+    }
+    (q, a)
+}
+
+pub fn remainder_fibonacci<S>(a: S, b: S) -> S
+where
+    S: LineSegment,
+{
+    // Precondition: b > 0
+    fibonacci_divide::<S, Uncounted>(a, b).1
+}
+
+pub fn quotient_fibonacci<S>(a: S, b: S) -> S::Quotient
+where
+    S: MeasurableSegment,
+{
+    // Precondition: b > 0
+    quotient_remainder_fibonacci(a, b).0
+}
+
+pub fn quotient_remainder_fibonacci<S>(a: S, b: S) -> (S::Quotient, S)
+where
+    S: MeasurableSegment,
+{
+    // Precondition: b > 0
+    let (Multiplier(q), r) = fibonacci_divide(a, b);
+    (q, r)
+}
+
+pub fn gcm_remainder<S>(mut a: S, mut b: S) -> S
+where
+    S: HalvableSegment,
+{
+    while !b.is_zero() {
+        a = remainder(a, b.clone());
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+
+// Why a division by a line segment cannot be carried out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DivisionError {
+    // b = 0: the doubling never gets past a
+    ZeroDivisor,
+    // a < 0 or b < 0, or either is NaN: a - b may overflow
+    NegativeSegment,
+    // a < b: largest_doubling has nothing to double
+    DivisorExceedsDividend,
+    // b + b is not exactly twice b, so halving it does not give b back:
+    // a is infinite, or the sum overflows
+    DoublingOverflow,
+}
+
+impl std::fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DivisionError::ZeroDivisor => write!(f, "division by a zero segment"),
+            DivisionError::NegativeSegment => write!(f, "negative segment"),
+            DivisionError::DivisorExceedsDividend => write!(f, "divisor longer than dividend"),
+            DivisionError::DoublingOverflow => write!(f, "doubling the divisor overflows"),
+        }
+    }
+}
+
+impl std::error::Error for DivisionError {}
+
+fn check_division<S>(a: &S, b: &S) -> Result<(), DivisionError>
+where
+    S: HalvableSegment,
+{
+    if b.is_zero() {
+        return Err(DivisionError::ZeroDivisor);
+    }
+    // !(x >= 0) rather than x < 0, so that NaN fails too
+    if !(*a >= S::zero() && *b >= S::zero()) {
+        return Err(DivisionError::NegativeSegment);
+    }
+    if *a >= *b {
+        // the division halves every doubling it makes, so each one must
+        // come back exactly; an infinite a would be doubled up to and
+        // then halved down from infinity forever
+        checked_largest_doubling(a, b.clone())?;
+    }
+    Ok(())
+}
+
+// largest_doubling, checking that each doubling halves back exactly
+fn checked_largest_doubling<S>(a: &S, mut b: S) -> Result<S, DivisionError>
+where
+    S: HalvableSegment,
+{
+    while a.clone() - b.clone() >= b {
+        let doubled = b.clone() + b.clone();
+        if !(doubled > b && doubled.clone().half() == b) {
+            return Err(DivisionError::DoublingOverflow);
+        }
+        b = doubled;
+    }
+    Ok(b)
+}
+
+pub fn try_largest_doubling<S>(a: &S, b: S) -> Result<S, DivisionError>
+where
+    S: HalvableSegment,
+{
+    check_division(a, &b)?;
+    if *a < b {
+        return Err(DivisionError::DivisorExceedsDividend);
+    }
+    Ok(largest_doubling(a, b))
+}
+
+pub fn try_remainder<S>(a: S, b: S) -> Result<S, DivisionError>
+where
+    S: HalvableSegment,
+{
+    check_division(&a, &b)?;
+    Ok(remainder(a, b))
+}
+
+pub fn try_quotient<S>(a: S, b: S) -> Result<S::Quotient, DivisionError>
+where
+    S: HalvableSegment,
+{
+    check_division(&a, &b)?;
+    Ok(quotient(a, b))
+}
+
+pub fn try_quotient_remainder<S>(a: S, b: S) -> Result<(S::Quotient, S), DivisionError>
+where
+    S: HalvableSegment,
+{
+    check_division(&a, &b)?;
+    Ok(quotient_remainder(a, b))
+}
+
+pub fn gcd<I>(mut a: I, mut b: I) -> I
+where
+    I: Clone + num_traits::Zero + std::ops::RemAssign,
+{
+    while !b.is_zero() {
+        a %= b.clone();
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
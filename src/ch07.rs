@@ -44,7 +44,6 @@ where
     Self: num_traits::Zero,
     Self: std::ops::Shr<Self, Output = Self>,
     Self: std::ops::Sub<Output = Self>,
-    Self: std::ops::Neg<Output = Self>,
     for<'a> &'a Self: std::ops::BitAnd<Self, Output = Self>,
 {
     fn odd(&self) -> bool {
@@ -64,7 +63,6 @@ where
     T: num_traits::Zero,
     T: std::ops::Shr<T, Output = T>,
     T: std::ops::Sub<Output = T>,
-    T: std::ops::Neg<Output = T>,
     for<'a> &'a Self: std::ops::BitAnd<Self, Output = Self>,
 {
 }
@@ -208,6 +206,7 @@ where
     fn multiply_group<N>(mut self, mut n: N) -> Self
     where
         N: Integer,
+        N: std::ops::Neg<Output = N>,
         for<'a> &'a N: std::ops::BitAnd<N, Output = N>,
         for<'a, 'b> &'a Self: std::ops::Add<&'b Self, Output = Self>,
     {
@@ -317,6 +316,7 @@ where
     fn power_group<N>(mut self, mut n: N) -> Self
     where
         N: Integer,
+        N: std::ops::Neg<Output = N>,
         for<'a> &'a N: std::ops::BitAnd<N, Output = N>,
         for<'a, 'b> &'a Self: std::ops::Mul<&'b Self, Output = Self>,
     {
@@ -338,7 +338,7 @@ where
 // Section 7.6
 
 pub trait SemigroupOperation<A> {
-    fn call(&self, &A, &A) -> A;
+    fn call(&self, x: &A, y: &A) -> A;
 
     fn power_accumulate_semigroup<N>(&self, mut r: A, mut a: A, mut n: N) -> A
     where
@@ -483,11 +483,12 @@ pub trait GroupOperation<A>
 where
     Self: MonoidOperation<A>,
 {
-    fn inverse_operation(&self, A) -> A;
+    fn inverse_operation(&self, x: A) -> A;
 
     fn power_group<N>(&self, mut a: A, mut n: N) -> A
     where
         N: Integer,
+        N: std::ops::Neg<Output = N>,
         for<'a> &'a N: std::ops::BitAnd<N, Output = N>,
     {
         if n < num_traits::zero() {
@@ -521,3 +522,62 @@ pub fn fibonacci_iterative(n: i32) -> i32 {
     }
     v.1
 }
+
+// The 2x2 matrices over T, which form a multiplicative monoid whenever
+// T is a semiring, so that the power algorithms above apply to them
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix2<T>(pub [[T; 2]; 2]);
+
+impl<T> std::ops::Mul<&Matrix2<T>> for &Matrix2<T>
+where
+    T: Clone + num_traits::Num,
+{
+    type Output = Matrix2<T>;
+
+    fn mul(self, other: &Matrix2<T>) -> Matrix2<T> {
+        let (x, y) = (&self.0, &other.0);
+        let entry = |i: usize, j: usize| {
+            x[i][0].clone() * y[0][j].clone() + x[i][1].clone() * y[1][j].clone()
+        };
+        Matrix2([[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]])
+    }
+}
+
+impl<T> std::ops::Mul for Matrix2<T>
+where
+    T: Clone + num_traits::Num,
+{
+    type Output = Matrix2<T>;
+
+    fn mul(self, other: Matrix2<T>) -> Matrix2<T> {
+        &self * &other
+    }
+}
+
+impl<T> num_traits::One for Matrix2<T>
+where
+    T: Clone + num_traits::Num,
+{
+    fn one() -> Matrix2<T> {
+        Matrix2([[T::one(), T::zero()], [T::zero(), T::one()]])
+    }
+}
+
+// [[1, 1], [1, 0]]^n = [[F(n + 1), F(n)], [F(n), F(n - 1)]], computed
+// with O(log n) matrix multiplications. Raising to n - 1 instead keeps
+// every entry within F(n), so that F(n) is found for every n for which
+// it fits in T.
+pub fn fibonacci<T, N>(n: N) -> T
+where
+    T: Clone + num_traits::Num,
+    N: Integer,
+    for<'a> &'a N: std::ops::BitAnd<N, Output = N>,
+{
+    // precondition(n >= 0);
+    if n == num_traits::zero() {
+        return T::zero();
+    }
+    let q = Matrix2([[T::one(), T::one()], [T::one(), T::zero()]]);
+    let Matrix2([[f, _], _]) = q.power_monoid(n - num_traits::one());
+    f
+}
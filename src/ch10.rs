@@ -120,7 +120,7 @@ pub mod fmgp {
             let next_is_none = {
                 let &mut (ref mut value, ref mut iterator) =
                     self.value_and_iterator.as_mut().unwrap();
-                iterator.next().map_or(true, |x| {
+                iterator.next().is_none_or(|x| {
                     *value = x;
                     false
                 })
@@ -147,7 +147,7 @@ pub mod fmgp {
     {
         let mut iterator = into_iter.into_iter();
         IteratorAdapter {
-            value_and_iterator: iterator.next().and_then(|value| Some((value, iterator))),
+            value_and_iterator: iterator.next().map(|value| (value, iterator)),
         }
     }
 
@@ -174,7 +174,7 @@ pub mod fmgp {
         I1: InputIterator<Item = I0::Item>,
         T: 'a,
     {
-        while let Some(x) = first0.next() {
+        for x in first0.by_ref() {
             match first1.next() {
                 Some(y) => swap(x, y),
                 None => break,
@@ -207,7 +207,7 @@ pub mod fmgp {
         let min = ::std::cmp::min(last0 - first0, last1 - first1);
         let p = (first0 + min, first1 + min);
         let (left, right) = slice.split_at_mut(first1);
-        swap_ranges(left[first0..p.0].iter_mut(), right[..min].iter_mut());
+        let _ = swap_ranges(left[first0..p.0].iter_mut(), right[..min].iter_mut());
         p
     }
 
@@ -469,13 +469,13 @@ pub mod fmgp {
         result
     }
 
-    fn reverse_n_with_buffer<T, N>(slice: &mut [T], f: usize, n: N, buffer: &mut [T])
+    fn reverse_n_with_buffer<T>(slice: &mut [T], f: usize, n: usize, buffer: &mut [T])
     where
         T: Clone,
-        N: Integer,
     {
-        copy_n(slice[f..].iter(), n, buffer.iter_mut());
-        reverse_copy(buffer.iter(), slice[f..].iter_mut());
+        // only the first n elements of the buffer hold the range
+        let _ = copy_n(slice[f..].iter(), n, buffer.iter_mut());
+        let _ = reverse_copy(buffer[..n].iter(), slice[f..].iter_mut());
     }
 
     // Section 11.7
@@ -497,7 +497,7 @@ pub mod fmgp {
         }
         if n <= buffer.len() {
             reverse_n_with_buffer(slice, f, n, buffer);
-            return n;
+            return f + n;
         }
         let h = n >> 1;
         let mut m = reverse_n_adaptive(slice, f, h, buffer);
@@ -91,7 +91,7 @@ where
 
 // Section 12.4
 
-fn _gcd<E>(mut a: E, mut b: E) -> E
+pub fn _gcd<E>(mut a: E, mut b: E) -> E
 where
     E: ::std::cmp::PartialEq,
     E: num_traits::Zero,
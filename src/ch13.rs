@@ -80,29 +80,32 @@ impl<I> ModuloMultiply<I> {
     fn new(i: I) -> ModuloMultiply<I> {
         ModuloMultiply { modulus: i }
     }
+}
 
-    fn apply(&self, n: &I, m: &I) -> I
-    where
-        for<'a, 'b> &'a I: ::std::ops::Mul<&'b I, Output = I>,
-        I: for<'a> ::std::ops::Rem<&'a I, Output = I>,
-    {
+impl<I> SemigroupOperation<I> for ModuloMultiply<I>
+where
+    for<'a, 'b> &'a I: ::std::ops::Mul<&'b I, Output = I>,
+    I: for<'a> ::std::ops::Rem<&'a I, Output = I>,
+{
+    fn call(&self, n: &I, m: &I) -> I {
         (n * m) % &self.modulus
     }
 }
 
-fn identity_element<I>(_: ModuloMultiply<I>) -> I
+impl<I> MonoidOperation<I> for ModuloMultiply<I>
 where
+    Self: SemigroupOperation<I>,
     I: num_traits::One,
 {
-    num_traits::one()
+    fn identity_element(&self) -> I {
+        num_traits::one()
+    }
 }
 
 pub fn multiplicative_inverse_fermat<I>(a: I, p: I) -> I
 where
-    I: num_traits::One,
-    I: ::std::ops::Sub<Output = I>,
-    I: num_integer::Integer,
-    I: ::std::ops::Shr<i32, Output = I>,
+    I: Integer,
+    for<'a> &'a I: ::std::ops::BitAnd<I, Output = I>,
     for<'a, 'b> &'a I: ::std::ops::Mul<&'b I, Output = I>,
     I: Clone,
     I: for<'a> ::std::ops::Rem<&'a I, Output = I>,
@@ -110,31 +113,20 @@ where
     // precondition: p is prime & a > 0
     let multiplier = ModuloMultiply::new(p.clone());
     let two = num_traits::one::<I>() + num_traits::one();
-    power_monoid_with_op(
-        a,
-        p - two,
-        &|x, y| multiplier.apply(x, y),
-        num_traits::one(),
-    )
+    multiplier.power_monoid(a, p - two)
 }
 
 pub fn fermat_test<I>(n: I, witness: I) -> bool
 where
-    I: ::std::ops::Sub<Output = I>,
-    I: num_traits::One,
-    I: num_integer::Integer,
-    I: ::std::ops::Shr<i32, Output = I>,
+    I: Integer,
+    for<'a> &'a I: ::std::ops::BitAnd<I, Output = I>,
     for<'a, 'b> &'a I: ::std::ops::Mul<&'b I, Output = I>,
     for<'a> I: ::std::ops::Rem<&'a I, Output = I>,
     I: Clone,
 {
     // precondition: 0 < witness < n
     let multiplier = ModuloMultiply::new(n.clone());
-    let remainder = power_semigroup_with_op(
-        witness,
-        n - num_traits::one(),
-        &|x, y| multiplier.apply(x, y),
-    );
+    let remainder = multiplier.power_semigroup(witness, n - num_traits::one());
     remainder == num_traits::one()
 }
 
@@ -142,8 +134,8 @@ where
 
 pub fn miller_rabin_test<I>(n: &I, q: I, k: &I, witness: I) -> bool
 where
-    I: num_integer::Integer,
-    I: ::std::ops::Shr<i32, Output = I>,
+    I: Integer,
+    for<'a> &'a I: ::std::ops::BitAnd<I, Output = I>,
     for<'a, 'b> &'a I: ::std::ops::Mul<&'b I, Output = I>,
     for<'a> I: ::std::ops::Rem<&'a I, Output = I>,
     I: ::std::ops::AddAssign,
@@ -153,7 +145,7 @@ where
     // precondition n > 1 && n - 1 = 2^kq && q is odd
 
     let mmult = ModuloMultiply::new(n.clone());
-    let mut x = power_semigroup_with_op(witness, q, &|x, y| mmult.apply(x, y));
+    let mut x = mmult.power_semigroup(witness, q);
     if x == num_traits::one() || x == n - num_traits::one() {
         return true;
     }
@@ -161,7 +153,7 @@ where
     while index < *k {
         // invariant x = w^{2^{i-1}q}
 
-        x = mmult.apply(&x, &x);
+        x = mmult.call(&x, &x);
         if x == n - num_traits::one() {
             return true;
         }
@@ -180,8 +172,8 @@ where
     I: ::std::cmp::PartialEq,
     I: num_traits::One,
     I: num_traits::Zero,
-    for<'a, 'b> &'a I: ::std::ops::Div<&'b I, Output = I>,
-    for<'a, 'b> &'a I: ::std::ops::Rem<&'b I, Output = I>,
+    for<'a> &'a I: ::std::ops::Div<Output = I>,
+    I: for<'a> ::std::ops::Rem<&'a I, Output = I>,
     I: ::std::ops::Sub<Output = I>,
     I: for<'a> ::std::ops::Mul<&'a I, Output = I>,
     I: ::std::cmp::PartialOrd,
@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------
+// continued_fraction.rs -- The quotients of Euclid's algorithm from
+// Chapter 4 of fM2GP, read as the continued fraction of a / b.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch04::*;
+use ratio::*;
+
+// The terms [q0; q1, q2, ...] of a / b: the successive quotients of
+// gcm_remainder
+pub fn continued_fraction<S>(mut a: S, mut b: S) -> Vec<S::Quotient>
+where
+    S: HalvableSegment,
+{
+    // precondition: a >= 0 && b > 0
+    let mut terms = Vec::new();
+    while !b.is_zero() {
+        let (q, r) = quotient_remainder(a, b.clone());
+        terms.push(q);
+        a = b;
+        b = r;
+    }
+    terms
+}
+
+// The numerators and denominators of the convergents of [q0; q1, ...]:
+// p(k) = q(k) p(k-1) + p(k-2), starting from p(-1) = 1, p(-2) = 0, and
+// likewise for q with q(-1) = 0, q(-2) = 1
+fn convergent_terms<E>(terms: &[E]) -> Vec<(E, E)>
+where
+    E: EuclideanDomain,
+{
+    let (mut p0, mut p1) = (E::zero(), E::one());
+    let (mut q0, mut q1) = (E::one(), E::zero());
+    terms
+        .iter()
+        .map(|t| {
+            let p2 = t.clone() * p1.clone() + p0.clone();
+            let q2 = t.clone() * q1.clone() + q0.clone();
+            p0 = std::mem::replace(&mut p1, p2.clone());
+            q0 = std::mem::replace(&mut q1, q2.clone());
+            (p2, q2)
+        })
+        .collect()
+}
+
+// The convergents of [q0; q1, ...]; the last one is the fraction itself
+pub fn convergents<E>(terms: &[E]) -> Vec<Ratio<E>>
+where
+    E: EuclideanDomain,
+{
+    convergent_terms(terms)
+        .into_iter()
+        .map(|(p, q)| Ratio::new(p, q))
+        .collect()
+}
+
+// The fraction p/q with q <= max_denom closest to a / b: the last
+// convergent within the bound, or the semiconvergent that follows it
+pub fn best_approximation<E>(a: E, b: E, max_denom: E) -> Ratio<E>
+where
+    E: HalvableSegment<Quotient = E> + EuclideanDomain,
+{
+    // precondition: a >= 0 && b > 0 && max_denom > 0
+    let x = Ratio::new(a.clone(), b.clone());
+    let terms = continued_fraction(a, b);
+    let (mut p0, mut p1) = (E::zero(), E::one());
+    let (mut q0, mut q1) = (E::one(), E::zero());
+    for t in terms {
+        let q2 = t.clone() * q1.clone() + q0.clone();
+        if q2 > max_denom {
+            // the largest semiconvergent (p0 + k p1) / (q0 + k q1) in bounds
+            let k = (max_denom - q0.clone()) / q1.clone();
+            let semiconvergent = Ratio::new(p0 + k.clone() * p1.clone(), q0 + k * q1.clone());
+            let convergent = Ratio::new(p1, q1);
+            return if distance(&x, &semiconvergent) < distance(&x, &convergent) {
+                semiconvergent
+            } else {
+                convergent
+            };
+        }
+        let p2 = t * p1.clone() + p0;
+        p0 = std::mem::replace(&mut p1, p2);
+        q0 = std::mem::replace(&mut q1, q2);
+    }
+    x
+}
+
+fn distance<E>(x: &Ratio<E>, y: &Ratio<E>) -> Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    if x < y {
+        y.clone() - x.clone()
+    } else {
+        x.clone() - y.clone()
+    }
+}
+
+// With p/q the next to last convergent of a / b, which has n terms,
+// a q - b p = (-1)^n gcd(a, b). Returns (x, gcd(a, b)) with
+// x a = gcd(a, b) (mod b), the pair extended_gcd of Chapter 12 returns,
+// but with 0 <= x < b / gcd(a, b).
+pub fn bezout_coefficient<E>(a: E, b: E) -> (E, E)
+where
+    E: HalvableSegment<Quotient = E> + EuclideanDomain,
+{
+    // precondition: a >= 0 && b > 0
+    let terms = continued_fraction(a.clone(), b.clone());
+    let n = terms.len();
+    let (p, q) = if n > 1 {
+        convergent_terms(&terms[..n - 1]).pop().unwrap()
+    } else {
+        (E::one(), E::zero())
+    };
+    if n.is_multiple_of(2) {
+        let gcd = a * q.clone() - b * p;
+        (q, gcd)
+    } else {
+        // a (b / gcd - q) = gcd (mod b)
+        let gcd = b.clone() * p - a * q.clone();
+        let m = b / gcd.clone();
+        ((m.clone() - q) % &m, gcd)
+    }
+}
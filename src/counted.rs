@@ -0,0 +1,390 @@
+// -------------------------------------------------------------------
+// counted.rs -- A numeric wrapper that counts the operations performed
+// on it, for comparing the algorithms of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate num_integer;
+extern crate num_traits;
+extern crate std;
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OperationCounts {
+    pub additions: u64,
+    pub subtractions: u64,
+    pub multiplications: u64,
+    pub divisions: u64,
+    pub remainders: u64,
+    pub negations: u64,
+    pub comparisons: u64,
+    pub shifts: u64,
+    pub bitwise: u64,
+}
+
+impl OperationCounts {
+    pub fn total(&self) -> u64 {
+        self.additions
+            + self.subtractions
+            + self.multiplications
+            + self.divisions
+            + self.remainders
+            + self.negations
+            + self.comparisons
+            + self.shifts
+            + self.bitwise
+    }
+}
+
+impl Sub for OperationCounts {
+    type Output = OperationCounts;
+
+    fn sub(self, other: OperationCounts) -> OperationCounts {
+        OperationCounts {
+            additions: self.additions - other.additions,
+            subtractions: self.subtractions - other.subtractions,
+            multiplications: self.multiplications - other.multiplications,
+            divisions: self.divisions - other.divisions,
+            remainders: self.remainders - other.remainders,
+            negations: self.negations - other.negations,
+            comparisons: self.comparisons - other.comparisons,
+            shifts: self.shifts - other.shifts,
+            bitwise: self.bitwise - other.bitwise,
+        }
+    }
+}
+
+impl Add for OperationCounts {
+    type Output = OperationCounts;
+
+    fn add(self, other: OperationCounts) -> OperationCounts {
+        OperationCounts {
+            additions: self.additions + other.additions,
+            subtractions: self.subtractions + other.subtractions,
+            multiplications: self.multiplications + other.multiplications,
+            divisions: self.divisions + other.divisions,
+            remainders: self.remainders + other.remainders,
+            negations: self.negations + other.negations,
+            comparisons: self.comparisons + other.comparisons,
+            shifts: self.shifts + other.shifts,
+            bitwise: self.bitwise + other.bitwise,
+        }
+    }
+}
+
+impl std::fmt::Display for OperationCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "+ {}, - {}, * {}, / {}, % {}, neg {}, cmp {}, shift {}, bit {}",
+            self.additions,
+            self.subtractions,
+            self.multiplications,
+            self.divisions,
+            self.remainders,
+            self.negations,
+            self.comparisons,
+            self.shifts,
+            self.bitwise
+        )
+    }
+}
+
+thread_local! {
+    static COUNTS: Cell<OperationCounts> = Cell::new(OperationCounts::default());
+}
+
+fn count<F>(field: F)
+where
+    F: FnOnce(&mut OperationCounts) -> &mut u64,
+{
+    COUNTS.with(|counts| {
+        let mut c = counts.get();
+        *field(&mut c) += 1;
+        counts.set(c);
+    })
+}
+
+// Counts of the operations performed on Counted values by this thread
+pub fn operation_counts() -> OperationCounts {
+    COUNTS.with(|counts| counts.get())
+}
+
+pub fn reset_operation_counts() {
+    COUNTS.with(|counts| counts.set(OperationCounts::default()))
+}
+
+// Runs f and returns its result together with the operations it
+// performed; counts from before the call are left in place.
+pub fn count_operations<F, R>(f: F) -> (R, OperationCounts)
+where
+    F: FnOnce() -> R,
+{
+    let before = operation_counts();
+    let result = f();
+    (result, operation_counts() - before)
+}
+
+// Counted(n) behaves like n but records every operation performed on
+// it. The operations are implemented for the primitive integer types.
+#[derive(Clone, Copy, Debug, Default, Hash)]
+pub struct Counted<T>(pub T);
+
+macro_rules! counted_binary_op {
+    ($t:ty, $op:ident, $method:ident, $field:ident) => {
+        impl $op for Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn $method(self, other: Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.$field);
+                Counted($op::$method(self.0, other.0))
+            }
+        }
+
+        impl<'b> $op<&'b Counted<$t>> for Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn $method(self, other: &'b Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.$field);
+                Counted($op::$method(self.0, other.0))
+            }
+        }
+
+        impl<'a> $op<Counted<$t>> for &'a Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn $method(self, other: Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.$field);
+                Counted($op::$method(self.0, other.0))
+            }
+        }
+
+        impl<'a, 'b> $op<&'b Counted<$t>> for &'a Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn $method(self, other: &'b Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.$field);
+                Counted($op::$method(self.0, other.0))
+            }
+        }
+    };
+}
+
+macro_rules! counted_assign_op {
+    ($t:ty, $op:ident, $method:ident, $field:ident) => {
+        impl $op for Counted<$t> {
+            fn $method(&mut self, other: Counted<$t>) {
+                count(|c| &mut c.$field);
+                $op::$method(&mut self.0, other.0)
+            }
+        }
+    };
+}
+
+macro_rules! counted_impl {
+    ($($t:ty)*) => ($(
+        counted_binary_op!($t, Add, add, additions);
+        counted_binary_op!($t, Sub, sub, subtractions);
+        counted_binary_op!($t, Mul, mul, multiplications);
+        counted_binary_op!($t, Div, div, divisions);
+        counted_binary_op!($t, Rem, rem, remainders);
+        counted_binary_op!($t, BitAnd, bitand, bitwise);
+        counted_binary_op!($t, Shl, shl, shifts);
+        counted_binary_op!($t, Shr, shr, shifts);
+
+        counted_assign_op!($t, AddAssign, add_assign, additions);
+        counted_assign_op!($t, SubAssign, sub_assign, subtractions);
+        counted_assign_op!($t, MulAssign, mul_assign, multiplications);
+        counted_assign_op!($t, DivAssign, div_assign, divisions);
+        counted_assign_op!($t, RemAssign, rem_assign, remainders);
+        counted_assign_op!($t, ShlAssign, shl_assign, shifts);
+        counted_assign_op!($t, ShrAssign, shr_assign, shifts);
+
+        // shifts by a plain amount, as in n >> 1 and m << d
+        impl Shl<i32> for Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn shl(self, amount: i32) -> Counted<$t> {
+                count(|c| &mut c.shifts);
+                Counted(self.0 << amount)
+            }
+        }
+
+        impl Shr<i32> for Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn shr(self, amount: i32) -> Counted<$t> {
+                count(|c| &mut c.shifts);
+                Counted(self.0 >> amount)
+            }
+        }
+
+        impl PartialEq for Counted<$t> {
+            fn eq(&self, other: &Counted<$t>) -> bool {
+                count(|c| &mut c.comparisons);
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Counted<$t> {}
+
+        impl PartialOrd for Counted<$t> {
+            fn partial_cmp(&self, other: &Counted<$t>) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Counted<$t> {
+            fn cmp(&self, other: &Counted<$t>) -> Ordering {
+                count(|c| &mut c.comparisons);
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl std::fmt::Display for Counted<$t> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl num_traits::Zero for Counted<$t> {
+            fn zero() -> Counted<$t> {
+                Counted(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                count(|c| &mut c.comparisons);
+                self.0 == 0
+            }
+        }
+
+        impl num_traits::One for Counted<$t> {
+            fn one() -> Counted<$t> {
+                Counted(1)
+            }
+        }
+
+        impl num_traits::Num for Counted<$t> {
+            type FromStrRadixErr = std::num::ParseIntError;
+
+            fn from_str_radix(s: &str, radix: u32) -> Result<Counted<$t>, Self::FromStrRadixErr> {
+                <$t>::from_str_radix(s, radix).map(Counted)
+            }
+        }
+
+        impl num_integer::Integer for Counted<$t> {
+            fn div_floor(&self, other: &Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.divisions);
+                Counted(num_integer::Integer::div_floor(&self.0, &other.0))
+            }
+
+            fn mod_floor(&self, other: &Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.remainders);
+                Counted(num_integer::Integer::mod_floor(&self.0, &other.0))
+            }
+
+            fn gcd(&self, other: &Counted<$t>) -> Counted<$t> {
+                Counted(num_integer::Integer::gcd(&self.0, &other.0))
+            }
+
+            fn lcm(&self, other: &Counted<$t>) -> Counted<$t> {
+                Counted(num_integer::Integer::lcm(&self.0, &other.0))
+            }
+
+            fn divides(&self, other: &Counted<$t>) -> bool {
+                self.is_multiple_of(other)
+            }
+
+            fn is_multiple_of(&self, other: &Counted<$t>) -> bool {
+                count(|c| &mut c.remainders);
+                num_integer::Integer::is_multiple_of(&self.0, &other.0)
+            }
+
+            fn is_even(&self) -> bool {
+                count(|c| &mut c.bitwise);
+                num_integer::Integer::is_even(&self.0)
+            }
+
+            fn is_odd(&self) -> bool {
+                count(|c| &mut c.bitwise);
+                num_integer::Integer::is_odd(&self.0)
+            }
+
+            fn div_rem(&self, other: &Counted<$t>) -> (Counted<$t>, Counted<$t>) {
+                count(|c| &mut c.divisions);
+                count(|c| &mut c.remainders);
+                let (q, r) = num_integer::Integer::div_rem(&self.0, &other.0);
+                (Counted(q), Counted(r))
+            }
+        }
+    )*)
+}
+
+counted_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+macro_rules! counted_signed_impl {
+    ($($t:ty)*) => ($(
+        impl Neg for Counted<$t> {
+            type Output = Counted<$t>;
+
+            fn neg(self) -> Counted<$t> {
+                count(|c| &mut c.negations);
+                Counted(-self.0)
+            }
+        }
+
+        impl num_traits::Signed for Counted<$t> {
+            fn abs(&self) -> Counted<$t> {
+                Counted(self.0.abs())
+            }
+
+            fn abs_sub(&self, other: &Counted<$t>) -> Counted<$t> {
+                count(|c| &mut c.subtractions);
+                Counted(num_traits::Signed::abs_sub(&self.0, &other.0))
+            }
+
+            fn signum(&self) -> Counted<$t> {
+                Counted(self.0.signum())
+            }
+
+            fn is_positive(&self) -> bool {
+                count(|c| &mut c.comparisons);
+                self.0 > 0
+            }
+
+            fn is_negative(&self) -> bool {
+                count(|c| &mut c.comparisons);
+                self.0 < 0
+            }
+        }
+    )*)
+}
+
+counted_signed_impl! { i8 i16 i32 i64 i128 isize }
+
+macro_rules! counted_unsigned_impl {
+    ($($t:ty)*) => ($(
+        impl num_traits::Unsigned for Counted<$t> {}
+    )*)
+}
+
+counted_unsigned_impl! { u8 u16 u32 u64 u128 usize }
+
+// Counted line segments, for counting the measure algorithms of Chapter 4
+#[cfg(feature = "ch04")]
+macro_rules! counted_segment_impl {
+    ($($t:ty)*) => ($(
+        impl ::ch04::HalvableSegment for Counted<$t> {
+            type Quotient = Counted<$t>;
+
+            fn half(self) -> Counted<$t> {
+                self >> 1
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "ch04")]
+counted_segment_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
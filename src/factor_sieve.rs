@@ -0,0 +1,126 @@
+// -------------------------------------------------------------------
+// factor_sieve.rs -- A variant of the sieve of Chapter 3 of fM2GP that
+// records the smallest prime factor of every number instead of a flag,
+// and the factorizations read off it.
+// -------------------------------------------------------------------
+
+extern crate std;
+
+// The smallest prime factor of each of 0, 1, ..., n; entries 0 and 1
+// hold 0 and 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SmallestFactors {
+    table: Vec<u32>,
+}
+
+impl SmallestFactors {
+    pub fn new(n: u32) -> SmallestFactors {
+        let n = n as usize;
+        let mut table = vec![0u32; n + 1];
+        for entry in table.iter_mut().skip(2).step_by(2) {
+            *entry = 2;
+        }
+        // as in sift, each odd prime factor crosses out its odd multiples
+        // from factor^2 on; the smallest factor gets there first
+        let mut factor = 3;
+        let mut square = 9;
+        while square <= n {
+            // invariant: square = factor^2
+            if table[factor] == 0 {
+                for m in (square..=n).step_by(2 * factor) {
+                    if table[m] == 0 {
+                        table[m] = factor as u32;
+                    }
+                }
+            }
+            square += 4 * factor + 4;
+            factor += 2;
+        }
+        // whatever is left has no factor below its square root
+        for (i, entry) in table.iter_mut().enumerate().skip(1) {
+            if *entry == 0 {
+                *entry = i as u32;
+            }
+        }
+        SmallestFactors { table }
+    }
+
+    // The largest number in the table
+    pub fn limit(&self) -> u32 {
+        (self.table.len() - 1) as u32
+    }
+
+    pub fn smallest_factor(&self, m: u32) -> u32 {
+        self.table[m as usize]
+    }
+
+    pub fn is_prime(&self, m: u32) -> bool {
+        m > 1 && self.smallest_factor(m) == m
+    }
+
+    // The prime factors of m with their exponents, smallest prime first.
+    // Each step divides by a prime, so there are at most log2(m) steps.
+    pub fn factorize(&self, mut m: u32) -> Vec<(u32, u32)> {
+        // precondition: 0 < m <= self.limit()
+        assert!(m > 0, "0 has no factorization");
+        let mut factors: Vec<(u32, u32)> = Vec::new();
+        while m > 1 {
+            let p = self.smallest_factor(m);
+            match factors.last_mut() {
+                Some(&mut (q, ref mut exponent)) if q == p => *exponent += 1,
+                _ => factors.push((p, 1)),
+            }
+            m /= p;
+        }
+        factors
+    }
+
+    // The divisors of m in increasing order
+    pub fn divisors(&self, m: u32) -> Vec<u32> {
+        // precondition: 0 < m <= self.limit()
+        let mut divisors = vec![1];
+        for (p, exponent) in self.factorize(m) {
+            let count = divisors.len();
+            let mut power = 1;
+            for _ in 0..exponent {
+                power *= p;
+                for i in 0..count {
+                    divisors.push(divisors[i] * power);
+                }
+            }
+        }
+        divisors.sort_unstable();
+        divisors
+    }
+
+    // Number of divisors of m
+    pub fn tau(&self, m: u32) -> u32 {
+        // precondition: 0 < m <= self.limit()
+        self.factorize(m)
+            .iter()
+            .map(|&(_, exponent)| exponent + 1)
+            .product()
+    }
+
+    // Sum of the divisors of m
+    pub fn sigma(&self, m: u32) -> u64 {
+        // precondition: 0 < m <= self.limit()
+        self.factorize(m)
+            .iter()
+            .map(|&(p, exponent)| {
+                // 1 + p + ... + p^exponent
+                let p = u64::from(p);
+                (0..exponent).fold(1, |sum, _| sum * p + 1)
+            })
+            .product()
+    }
+
+    // Number of 1 <= k <= m coprime to m
+    pub fn euler_phi(&self, m: u32) -> u32 {
+        // precondition: 0 < m <= self.limit()
+        self.factorize(m)
+            .iter()
+            .map(|&(p, exponent)| (p - 1) * p.pow(exponent - 1))
+            .product()
+    }
+}
@@ -0,0 +1,86 @@
+// -------------------------------------------------------------------
+// fixed_point.rs -- Decimal fractions with a fixed number of places,
+// stored as a whole number of units of 10^-PLACES.
+// -------------------------------------------------------------------
+
+extern crate num_traits;
+extern crate std;
+use ch04::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const PLACES: u32> {
+    units: u64,
+}
+
+impl<const PLACES: u32> Fixed<PLACES> {
+    pub fn from_units(units: u64) -> Fixed<PLACES> {
+        Fixed { units }
+    }
+
+    pub fn from_integer(n: u64) -> Fixed<PLACES> {
+        Fixed {
+            units: n * 10u64.pow(PLACES),
+        }
+    }
+
+    pub fn units(&self) -> u64 {
+        self.units
+    }
+}
+
+impl<const PLACES: u32> std::ops::Add for Fixed<PLACES> {
+    type Output = Fixed<PLACES>;
+
+    fn add(self, other: Fixed<PLACES>) -> Fixed<PLACES> {
+        Fixed {
+            units: self.units + other.units,
+        }
+    }
+}
+
+impl<const PLACES: u32> std::ops::Sub for Fixed<PLACES> {
+    type Output = Fixed<PLACES>;
+
+    fn sub(self, other: Fixed<PLACES>) -> Fixed<PLACES> {
+        Fixed {
+            units: self.units - other.units,
+        }
+    }
+}
+
+impl<const PLACES: u32> num_traits::Zero for Fixed<PLACES> {
+    fn zero() -> Fixed<PLACES> {
+        Fixed { units: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.units == 0
+    }
+}
+
+impl<const PLACES: u32> HalvableSegment for Fixed<PLACES> {
+    type Quotient = u64;
+
+    fn half(self) -> Fixed<PLACES> {
+        Fixed {
+            units: self.units >> 1,
+        }
+    }
+}
+
+impl<const PLACES: u32> std::fmt::Display for Fixed<PLACES> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let scale = 10u64.pow(PLACES);
+        if PLACES == 0 {
+            write!(f, "{}", self.units)
+        } else {
+            write!(
+                f,
+                "{}.{:0width$}",
+                self.units / scale,
+                self.units % scale,
+                width = PLACES as usize
+            )
+        }
+    }
+}
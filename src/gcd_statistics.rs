@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------
+// gcd_statistics.rs -- How much work the gcd algorithms of Chapters 4
+// and 12 of fM2GP do, counted on Counted values, and a check of Lamé's
+// bound on the number of steps of Euclid's algorithm.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch04::*;
+use ch12::*;
+use counted::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GcdAlgorithm {
+    Gcm0,
+    Gcm1,
+    Gcm,
+    FastSegmentGcm,
+    GcmRemainder,
+    Gcd,
+    SteinGcd,
+}
+
+impl GcdAlgorithm {
+    // The name of the function that implements it
+    pub fn name(self) -> &'static str {
+        match self {
+            GcdAlgorithm::Gcm0 => "gcm0",
+            GcdAlgorithm::Gcm1 => "gcm1",
+            GcdAlgorithm::Gcm => "gcm",
+            GcdAlgorithm::FastSegmentGcm => "fast_segment_gcm",
+            GcdAlgorithm::GcmRemainder => "gcm_remainder",
+            GcdAlgorithm::Gcd => "gcd",
+            GcdAlgorithm::SteinGcd => "stein_gcd",
+        }
+    }
+}
+
+impl std::fmt::Display for GcdAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub const ALGORITHMS: [GcdAlgorithm; 7] = [
+    GcdAlgorithm::Gcm0,
+    GcdAlgorithm::Gcm1,
+    GcdAlgorithm::Gcm,
+    GcdAlgorithm::FastSegmentGcm,
+    GcdAlgorithm::GcmRemainder,
+    GcdAlgorithm::Gcd,
+    GcdAlgorithm::SteinGcd,
+];
+
+// The operations algorithm performs on gcd(a, b)
+pub fn gcd_operations(algorithm: GcdAlgorithm, a: i64, b: i64) -> OperationCounts {
+    // precondition: a > 0 && b > 0, as the gcm algorithms need
+    let (a, b) = (Counted(a), Counted(b));
+    count_operations(|| match algorithm {
+        GcdAlgorithm::Gcm0 => gcm0(a, b),
+        GcdAlgorithm::Gcm1 => gcm1(a, b),
+        GcdAlgorithm::Gcm => gcm(a, b),
+        GcdAlgorithm::FastSegmentGcm => fast_segment_gcm(a, b),
+        GcdAlgorithm::GcmRemainder => gcm_remainder(a, b),
+        GcdAlgorithm::Gcd => gcd(a, b),
+        GcdAlgorithm::SteinGcd => stein_gcd(a, b),
+    })
+    .1
+}
+
+pub fn is_consecutive_fibonacci(a: i64, b: i64) -> bool {
+    let (mut f0, mut f1) = (1, 1);
+    while f1 < a {
+        let f2 = f0 + f1;
+        f0 = f1;
+        f1 = f2;
+    }
+    (f1, f0) == (a, b)
+}
+
+// Why there are no pairs lo <= b <= a <= hi to measure
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    // lo <= 0: the gcm algorithms need positive segments
+    NotPositive,
+    // lo > hi: the range is empty
+    Empty,
+}
+
+impl std::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RangeError::NotPositive => write!(f, "the gcm algorithms need positive segments"),
+            RangeError::Empty => write!(f, "empty range: lo is greater than hi"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+fn check_range(lo: i64, hi: i64) -> Result<(), RangeError> {
+    if lo <= 0 {
+        return Err(RangeError::NotPositive);
+    }
+    if lo > hi {
+        return Err(RangeError::Empty);
+    }
+    Ok(())
+}
+
+// The pairs lo <= b <= a <= hi, in increasing order of a, then b
+fn pairs(lo: i64, hi: i64) -> impl Iterator<Item = (i64, i64)> {
+    // precondition: check_range(lo, hi) is Ok
+    (lo..=hi).flat_map(move |a| (lo..=a).map(move |b| (a, b)))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GcdStatistics {
+    pub algorithm: GcdAlgorithm,
+    pub pairs: u64,
+    pub mean_operations: f64,
+    pub mean_subtractions: f64,
+    pub mean_remainders: f64,
+    pub mean_shifts: f64,
+    pub max_operations: u64,
+    // the first pair needing max_operations
+    pub worst_case: (i64, i64),
+}
+
+// Statistics of every algorithm over the pairs lo <= b <= a <= hi
+pub fn gcd_statistics(lo: i64, hi: i64) -> Result<Vec<GcdStatistics>, RangeError> {
+    check_range(lo, hi)?;
+    Ok(ALGORITHMS
+        .iter()
+        .map(|&algorithm| {
+            let mut total = OperationCounts::default();
+            let mut count = 0;
+            let mut max_operations = 0;
+            let mut worst_case = (lo, lo);
+            for (a, b) in pairs(lo, hi) {
+                let counts = gcd_operations(algorithm, a, b);
+                if counts.total() > max_operations {
+                    max_operations = counts.total();
+                    worst_case = (a, b);
+                }
+                total = total + counts;
+                count += 1;
+            }
+            let mean = |n: u64| n as f64 / std::cmp::max(count, 1) as f64;
+            GcdStatistics {
+                algorithm,
+                pairs: count,
+                mean_operations: mean(total.total()),
+                mean_subtractions: mean(total.subtractions),
+                mean_remainders: mean(total.remainders),
+                mean_shifts: mean(total.shifts),
+                max_operations,
+                worst_case,
+            }
+        })
+        .collect())
+}
+
+// Lamé: Euclid's algorithm on a >= b takes at most five steps for each
+// decimal digit of b. The inputs needing the most steps for their size
+// are consecutive Fibonacci numbers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LameCheck {
+    pub pairs: u64,
+    // the most remainder steps gcd took, and the first pair taking them
+    pub max_steps: u64,
+    pub worst_case: (i64, i64),
+    // the first pair taking more steps than the bound allows
+    pub counterexample: Option<(i64, i64)>,
+}
+
+impl LameCheck {
+    pub fn holds(&self) -> bool {
+        self.counterexample.is_none()
+    }
+}
+
+fn decimal_digits(mut n: i64) -> u64 {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+pub fn lame_check(lo: i64, hi: i64) -> Result<LameCheck, RangeError> {
+    check_range(lo, hi)?;
+    let mut check = LameCheck {
+        pairs: 0,
+        max_steps: 0,
+        worst_case: (lo, lo),
+        counterexample: None,
+    };
+    for (a, b) in pairs(lo, hi) {
+        let steps = gcd_operations(GcdAlgorithm::Gcd, a, b).remainders;
+        if steps > check.max_steps {
+            check.max_steps = steps;
+            check.worst_case = (a, b);
+        }
+        if steps > 5 * decimal_digits(b) && check.counterexample.is_none() {
+            check.counterexample = Some((a, b));
+        }
+        check.pairs += 1;
+    }
+    Ok(check)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableFormat {
+    Csv,
+    Markdown,
+}
+
+fn table(header: &[&str], rows: &[Vec<String>], format: TableFormat) -> String {
+    let line = |cells: Vec<String>| match format {
+        TableFormat::Csv => cells.join(","),
+        TableFormat::Markdown => format!("| {} |", cells.join(" | ")),
+    };
+    let mut lines = vec![line(header.iter().map(|h| h.to_string()).collect())];
+    if format == TableFormat::Markdown {
+        lines.push(line(header.iter().map(|_| "---".to_string()).collect()));
+    }
+    lines.extend(rows.iter().map(|row| line(row.clone())));
+    lines.join("\n")
+}
+
+pub fn statistics_table(statistics: &[GcdStatistics], format: TableFormat) -> String {
+    let header = [
+        "algorithm",
+        "pairs",
+        "mean operations",
+        "mean subtractions",
+        "mean remainders",
+        "mean shifts",
+        "max operations",
+        "worst a",
+        "worst b",
+        "consecutive Fibonacci",
+    ];
+    let rows: Vec<Vec<String>> = statistics
+        .iter()
+        .map(|s| {
+            vec![
+                s.algorithm.to_string(),
+                s.pairs.to_string(),
+                format!("{:.2}", s.mean_operations),
+                format!("{:.2}", s.mean_subtractions),
+                format!("{:.2}", s.mean_remainders),
+                format!("{:.2}", s.mean_shifts),
+                s.max_operations.to_string(),
+                s.worst_case.0.to_string(),
+                s.worst_case.1.to_string(),
+                is_consecutive_fibonacci(s.worst_case.0, s.worst_case.1).to_string(),
+            ]
+        })
+        .collect();
+    table(&header, &rows, format)
+}
+
+pub fn lame_table(check: &LameCheck, format: TableFormat) -> String {
+    let header = [
+        "pairs",
+        "max steps",
+        "worst a",
+        "worst b",
+        "consecutive Fibonacci",
+        "bound holds",
+    ];
+    let row = vec![
+        check.pairs.to_string(),
+        check.max_steps.to_string(),
+        check.worst_case.0.to_string(),
+        check.worst_case.1.to_string(),
+        is_consecutive_fibonacci(check.worst_case.0, check.worst_case.1).to_string(),
+        check.holds().to_string(),
+    ];
+    table(&header, &[row], format)
+}
@@ -0,0 +1,62 @@
+// -------------------------------------------------------------------
+// lib.rs -- The functions of fM2GP, one module per chapter. Each
+// chapter can be enabled on its own with the cargo feature of the same
+// name; all of them are enabled by default.
+// -------------------------------------------------------------------
+
+#[cfg(feature = "ch02")]
+pub mod ch02;
+#[cfg(feature = "ch02")]
+pub mod trace;
+
+#[cfg(feature = "ch03")]
+pub mod ch03;
+#[cfg(feature = "ch03")]
+pub mod factor_sieve;
+#[cfg(feature = "ch03")]
+pub mod primes;
+#[cfg(feature = "ch03")]
+pub mod segmented_sieve;
+#[cfg(feature = "ch03")]
+pub mod special_numbers;
+#[cfg(feature = "ch03")]
+pub mod wheel_sieve;
+
+#[cfg(feature = "ch04")]
+pub mod ch04;
+#[cfg(feature = "ch04")]
+pub mod fixed_point;
+
+#[cfg(feature = "ch07")]
+pub mod addition_chain;
+#[cfg(feature = "ch07")]
+pub mod ch07;
+
+#[cfg(feature = "ch08")]
+pub mod ch08;
+
+#[cfg(feature = "ch10")]
+pub mod ch10;
+
+#[cfg(feature = "ch11")]
+pub mod ch11;
+
+#[cfg(feature = "ch12")]
+pub mod ch12;
+#[cfg(feature = "ch12")]
+pub mod multiple_gcd;
+#[cfg(feature = "ch12")]
+pub mod ratio;
+
+#[cfg(feature = "ch13")]
+pub mod ch13;
+#[cfg(feature = "ch13")]
+pub mod counted;
+
+// needs the Ratio of ch12 as well as ch04
+#[cfg(all(feature = "ch04", feature = "ch12"))]
+pub mod continued_fraction;
+
+// needs the Counted values of ch13 as well as ch04
+#[cfg(all(feature = "ch04", feature = "ch13"))]
+pub mod gcd_statistics;
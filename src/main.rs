@@ -0,0 +1,581 @@
+// -------------------------------------------------------------------
+// main.rs -- fm2gp, a command-line front end to the algorithms of
+// fM2GP.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+
+use fm2gp::ch07::{MonoidOperation, SemigroupOperation};
+use fm2gp::ch10::fmgp as ch10;
+use fm2gp::ch11::fmgp as ch11;
+use fm2gp::gcd_statistics::{self, TableFormat};
+use fm2gp::{ch04, ch12, ch13, primes};
+use std::io::BufRead;
+use std::str::FromStr;
+
+const USAGE: &str = "usage: fm2gp [--json] <command> [options] [operands]
+
+commands:
+  gcd [--algorithm euclid|stein|gcm|fast_segment] A B
+  egcd A B                    gcd and x, y with a * x + b * y = gcd
+  gcd-stats [--format markdown|csv] LO HI
+                              operations of each gcd algorithm over the
+                              pairs LO <= B <= A <= HI, and Lame's bound
+  sieve N                     primes up to N
+  is-prime N                  deterministic Miller-Rabin for N < 2^64
+  miller-rabin N [WITNESS...] each WITNESS in [2, N - 2]
+  inverse A N                 multiplicative inverse of A modulo N
+  power A N [MODULUS]         A to the N, optionally modulo MODULUS
+  rotate [--algorithm gries_mills|forward|random_access|three_reverse|bidirectional] M X...
+  reverse [--algorithm iterative|recursive|adaptive] X...
+  partition-point [--upper] VALUE X...
+                              first index of the sorted X... whose element
+                              is not less than (--upper: greater than) VALUE
+
+When no operands are given, each line of standard input is read as the
+operands of one invocation.";
+
+#[derive(Default)]
+struct Options {
+    json: bool,
+    algorithm: Option<String>,
+    format: Option<String>,
+    upper: bool,
+}
+
+// The result of one invocation: its plain text form and the fields of
+// its JSON object, whose values are already JSON encoded.
+struct Output {
+    plain: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Output {
+    fn json(&self) -> String {
+        let fields: Vec<String> = self
+            .fields
+            .iter()
+            .map(|&(name, ref value)| format!("\"{}\": {}", name, value))
+            .collect();
+        format!("{{{}}}", fields.join(", "))
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+fn json_array<T>(values: &[T]) -> String
+where
+    T: std::fmt::Display,
+{
+    let values: Vec<String> = values.iter().map(|x| x.to_string()).collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn json_strings(values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|x| json_string(x)).collect();
+    format!("[{}]", values.join(", "))
+}
+
+fn parse<T>(operand: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    operand
+        .parse()
+        .map_err(|_| format!("invalid number '{}'", operand))
+}
+
+fn expect_operands(operands: &[String], count: usize) -> Result<(), String> {
+    if operands.len() != count {
+        return Err(format!(
+            "expected {} operands, got {}",
+            count,
+            operands.len()
+        ));
+    }
+    Ok(())
+}
+
+fn algorithm<'a>(options: &'a Options, default: &'a str) -> &'a str {
+    options.algorithm.as_ref().map_or(default, |a| a.as_str())
+}
+
+fn gcd(options: &Options, operands: &[String]) -> Result<Output, String> {
+    expect_operands(operands, 2)?;
+    let algorithm = algorithm(options, "euclid");
+    let (a, b, gcd) = if algorithm == "stein" {
+        let (a, b) = (parse::<i64>(&operands[0])?, parse::<i64>(&operands[1])?);
+        // stein_gcd takes absolute values, and |i64::MIN| is not an i64
+        if a == i64::MIN || b == i64::MIN {
+            return Err(format!("stein needs operands greater than {}", i64::MIN));
+        }
+        (
+            a.to_string(),
+            b.to_string(),
+            ch12::stein_gcd(a, b).to_string(),
+        )
+    } else {
+        let (a, b) = (parse::<u32>(&operands[0])?, parse::<u32>(&operands[1])?);
+        let gcd = match algorithm {
+            "euclid" => ch04::gcd(a, b),
+            "gcm" | "fast_segment" if a == 0 || b == 0 => {
+                return Err(format!("{} needs positive operands", algorithm));
+            }
+            "gcm" => ch04::gcm(a, b),
+            "fast_segment" => ch04::fast_segment_gcm(a, b),
+            _ => return Err(format!("unknown gcd algorithm '{}'", algorithm)),
+        };
+        (a.to_string(), b.to_string(), gcd.to_string())
+    };
+    Ok(Output {
+        plain: gcd.clone(),
+        fields: vec![
+            ("algorithm", json_string(algorithm)),
+            ("a", a),
+            ("b", b),
+            ("gcd", gcd),
+        ],
+    })
+}
+
+fn egcd(operands: &[String]) -> Result<Output, String> {
+    expect_operands(operands, 2)?;
+    let (a, b) = (parse::<i64>(&operands[0])?, parse::<i64>(&operands[1])?);
+    // in i128, where neither the remainders nor a * x can overflow
+    let (a, b) = (i128::from(a), i128::from(b));
+    let (mut x, mut gcd) = ch12::extended_gcd(a, b);
+    if gcd < 0 {
+        x = -x;
+        gcd = -gcd;
+    }
+    // a * x + b * y = gcd
+    let y = if b == 0 { 0 } else { (gcd - a * x) / b };
+    Ok(Output {
+        plain: format!("{} {} {}", gcd, x, y),
+        fields: vec![
+            ("a", a.to_string()),
+            ("b", b.to_string()),
+            ("gcd", gcd.to_string()),
+            ("x", x.to_string()),
+            ("y", y.to_string()),
+        ],
+    })
+}
+
+fn gcd_stats(options: &Options, operands: &[String]) -> Result<Output, String> {
+    expect_operands(operands, 2)?;
+    let (lo, hi) = (parse::<i64>(&operands[0])?, parse::<i64>(&operands[1])?);
+    let format = match options.format.as_ref().map_or("markdown", |f| f.as_str()) {
+        "markdown" => TableFormat::Markdown,
+        "csv" => TableFormat::Csv,
+        format => return Err(format!("unknown table format '{}'", format)),
+    };
+    let statistics = gcd_statistics::gcd_statistics(lo, hi).map_err(|e| e.to_string())?;
+    let lame = gcd_statistics::lame_check(lo, hi).map_err(|e| e.to_string())?;
+    let separator = if format == TableFormat::Markdown {
+        "\n\n"
+    } else {
+        "\n"
+    };
+    let plain = format!(
+        "{}{}{}",
+        gcd_statistics::statistics_table(&statistics, format),
+        separator,
+        gcd_statistics::lame_table(&lame, format)
+    );
+    let algorithms: Vec<String> = statistics
+        .iter()
+        .map(|s| {
+            format!(
+                "{{\"algorithm\": {}, \"pairs\": {}, \"mean_operations\": {}, \"max_operations\": {}, \"worst_case\": {}}}",
+                json_string(s.algorithm.name()),
+                s.pairs,
+                s.mean_operations,
+                s.max_operations,
+                json_array(&[s.worst_case.0, s.worst_case.1])
+            )
+        })
+        .collect();
+    Ok(Output {
+        plain,
+        fields: vec![
+            ("lo", lo.to_string()),
+            ("hi", hi.to_string()),
+            ("algorithms", format!("[{}]", algorithms.join(", "))),
+            ("lame_max_steps", lame.max_steps.to_string()),
+            (
+                "lame_worst_case",
+                json_array(&[lame.worst_case.0, lame.worst_case.1]),
+            ),
+            ("lame_holds", lame.holds().to_string()),
+        ],
+    })
+}
+
+fn sieve(operands: &[String]) -> Result<Output, String> {
+    expect_operands(operands, 1)?;
+    let n = parse::<u64>(&operands[0])?;
+    let primes: Vec<u64> = primes::primes_up_to(n).collect();
+    let plain: Vec<String> = primes.iter().map(|p| p.to_string()).collect();
+    Ok(Output {
+        plain: plain.join(" "),
+        fields: vec![("n", n.to_string()), ("primes", json_array(&primes))],
+    })
+}
+
+fn is_prime(operands: &[String]) -> Result<Output, String> {
+    expect_operands(operands, 1)?;
+    let n = parse::<u64>(&operands[0])?;
+    let prime = miller_rabin_prime(n, &default_witnesses(n));
+    Ok(Output {
+        plain: prime.to_string(),
+        fields: vec![("n", n.to_string()), ("prime", prime.to_string())],
+    })
+}
+
+// These witnesses decide primality for every n < 2^64.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// The witnesses in [2, n - 2]; for n >= 5 they include 2, which alone
+// decides primality below 2047
+fn default_witnesses(n: u64) -> Vec<u64> {
+    WITNESSES.iter().cloned().filter(|&w| w + 2 <= n).collect()
+}
+
+// Whether n passes the Miller-Rabin test with every witness, each of
+// which must lie in [2, n - 2]. Even n and n < 5 are decided without
+// the test.
+fn miller_rabin_prime(n: u64, witnesses: &[u64]) -> bool {
+    if n < 5 || n.is_multiple_of(2) {
+        return n == 2 || n == 3;
+    }
+    // n - 1 = 2^k q with q odd; the products of the test need twice the
+    // width of n
+    let k = (n - 1).trailing_zeros();
+    let q = (n - 1) >> k;
+    witnesses.iter().all(|&w| {
+        ch13::miller_rabin_test(&u128::from(n), u128::from(q), &u128::from(k), u128::from(w))
+    })
+}
+
+fn miller_rabin(operands: &[String]) -> Result<Output, String> {
+    if operands.is_empty() {
+        return Err(String::from("expected N [WITNESS...]"));
+    }
+    let n = parse::<u64>(&operands[0])?;
+    let witnesses = if operands.len() > 1 {
+        let witnesses = operands[1..]
+            .iter()
+            .map(|w| parse::<u64>(w))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(w) = witnesses
+            .iter()
+            .find(|&&w| w < 2 || w > n.saturating_sub(2))
+        {
+            return Err(format!(
+                "witness {} is not in [2, n - 2] = [2, {}]",
+                w,
+                i128::from(n) - 2
+            ));
+        }
+        witnesses
+    } else {
+        default_witnesses(n)
+    };
+    let prime = miller_rabin_prime(n, &witnesses);
+    Ok(Output {
+        plain: prime.to_string(),
+        fields: vec![
+            ("n", n.to_string()),
+            ("witnesses", json_array(&witnesses)),
+            ("prime", prime.to_string()),
+        ],
+    })
+}
+
+fn inverse(operands: &[String]) -> Result<Output, String> {
+    expect_operands(operands, 2)?;
+    let (a, n) = (parse::<i64>(&operands[0])?, parse::<i64>(&operands[1])?);
+    if n < 2 {
+        return Err(String::from("the modulus must be at least 2"));
+    }
+    let x = ch13::multiplicative_inverse(a.rem_euclid(n), n);
+    if x == 0 {
+        return Err(format!("{} has no inverse modulo {}", a, n));
+    }
+    Ok(Output {
+        plain: x.to_string(),
+        fields: vec![
+            ("a", a.to_string()),
+            ("n", n.to_string()),
+            ("inverse", x.to_string()),
+        ],
+    })
+}
+
+// Multiplication that remembers overflow as None
+struct CheckedMultiplies();
+
+impl SemigroupOperation<Option<u128>> for CheckedMultiplies {
+    fn call(&self, x: &Option<u128>, y: &Option<u128>) -> Option<u128> {
+        x.and_then(|x| y.and_then(|y| x.checked_mul(y)))
+    }
+}
+
+impl MonoidOperation<Option<u128>> for CheckedMultiplies {
+    fn identity_element(&self) -> Option<u128> {
+        Some(1)
+    }
+}
+
+struct ModuloMultiplies(u128);
+
+impl SemigroupOperation<u128> for ModuloMultiplies {
+    fn call(&self, x: &u128, y: &u128) -> u128 {
+        x * y % self.0
+    }
+}
+
+impl MonoidOperation<u128> for ModuloMultiplies {
+    fn identity_element(&self) -> u128 {
+        1 % self.0
+    }
+}
+
+fn power(operands: &[String]) -> Result<Output, String> {
+    if operands.len() != 2 && operands.len() != 3 {
+        return Err(String::from("expected A N [MODULUS]"));
+    }
+    let (a, n) = (parse::<u64>(&operands[0])?, parse::<u64>(&operands[1])?);
+    let (power, modulus) = match operands.get(2) {
+        Some(modulus) => {
+            let modulus = parse::<u64>(modulus)?;
+            if modulus == 0 {
+                return Err(String::from("the modulus must be positive"));
+            }
+            // operands below 2^64 keep the products within a u128
+            let m = u128::from(modulus);
+            let power = ModuloMultiplies(m).power_monoid(u128::from(a) % m, n);
+            (power, modulus.to_string())
+        }
+        None => match CheckedMultiplies().power_monoid(Some(u128::from(a)), n) {
+            Some(power) => (power, String::from("null")),
+            None => return Err(format!("{}^{} does not fit in 128 bits", a, n)),
+        },
+    };
+    Ok(Output {
+        plain: power.to_string(),
+        fields: vec![
+            ("a", a.to_string()),
+            ("n", n.to_string()),
+            ("modulus", modulus),
+            ("power", power.to_string()),
+        ],
+    })
+}
+
+fn rotate(options: &Options, operands: &[String]) -> Result<Output, String> {
+    if operands.is_empty() {
+        return Err(String::from("expected M X..."));
+    }
+    let m = parse::<usize>(&operands[0])?;
+    let mut values = operands[1..].to_vec();
+    if m > values.len() {
+        return Err(format!("{} is past the end of the sequence", m));
+    }
+    match algorithm(options, "gries_mills") {
+        "gries_mills" => ch11::gries_mills_rotate(&mut values, m),
+        "forward" => {
+            ch11::rotate_forward(&mut values, m);
+        }
+        "random_access" => {
+            ch11::rotate_random_access(&mut values, m);
+        }
+        "three_reverse" => ch11::three_reverse_rotate(&mut values, m),
+        "bidirectional" => {
+            ch11::rotate_bidirectional(&mut values, m);
+        }
+        algorithm => return Err(format!("unknown rotate algorithm '{}'", algorithm)),
+    }
+    Ok(Output {
+        plain: values.join(" "),
+        fields: vec![("m", m.to_string()), ("values", json_strings(&values))],
+    })
+}
+
+fn reverse(options: &Options, operands: &[String]) -> Result<Output, String> {
+    let mut values = operands.to_vec();
+    let n = values.len();
+    match algorithm(options, "iterative") {
+        "iterative" => ch11::reverse_n(values.iter_mut(), n),
+        "recursive" => {
+            ch11::reverse_recursive(&mut values, 0, n);
+        }
+        "adaptive" => {
+            let mut buffer = values[..n / 4].to_vec();
+            ch11::reverse_n_adaptive(&mut values, 0, n, &mut buffer);
+        }
+        algorithm => return Err(format!("unknown reverse algorithm '{}'", algorithm)),
+    }
+    Ok(Output {
+        plain: values.join(" "),
+        fields: vec![("values", json_strings(&values))],
+    })
+}
+
+fn partition_point(options: &Options, operands: &[String]) -> Result<Output, String> {
+    if operands.is_empty() {
+        return Err(String::from("expected VALUE X..."));
+    }
+    let value = parse::<i64>(&operands[0])?;
+    let values = operands[1..]
+        .iter()
+        .map(|x| parse::<i64>(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    // the predicate must partition the sequence
+    if values.windows(2).any(|w| w[1] < w[0]) {
+        return Err(String::from("the sequence must be sorted"));
+    }
+    let f = ch10::begin_random_access(&values);
+    let l = ch10::end_random_access(&values);
+    let point = if options.upper {
+        ch10::upper_bound(f.clone(), &l, &value)
+    } else {
+        ch10::lower_bound(f.clone(), &l, &value)
+    };
+    let index = ch10::distance_random_access(f, point);
+    Ok(Output {
+        plain: index.to_string(),
+        fields: vec![
+            ("value", value.to_string()),
+            ("values", json_array(&values)),
+            ("index", index.to_string()),
+        ],
+    })
+}
+
+const COMMANDS: [&str; 11] = [
+    "gcd",
+    "egcd",
+    "gcd-stats",
+    "sieve",
+    "is-prime",
+    "miller-rabin",
+    "inverse",
+    "power",
+    "rotate",
+    "reverse",
+    "partition-point",
+];
+
+fn run(command: &str, options: &Options, operands: &[String]) -> Result<Output, String> {
+    match command {
+        "gcd" => gcd(options, operands),
+        "egcd" => egcd(operands),
+        "gcd-stats" => gcd_stats(options, operands),
+        "sieve" => sieve(operands),
+        "is-prime" => is_prime(operands),
+        "miller-rabin" => miller_rabin(operands),
+        "inverse" => inverse(operands),
+        "power" => power(operands),
+        "rotate" => rotate(options, operands),
+        "reverse" => reverse(options, operands),
+        "partition-point" => partition_point(options, operands),
+        _ => unreachable!(),
+    }
+}
+
+// Prints the output of one invocation, or its error; returns whether it
+// succeeded.
+fn report(command: &str, options: &Options, operands: &[String]) -> bool {
+    match run(command, options, operands) {
+        Ok(output) => {
+            if options.json {
+                println!("{}", output.json());
+            } else {
+                println!("{}", output.plain);
+            }
+            true
+        }
+        Err(message) => {
+            eprintln!("fm2gp {}: {}", command, message);
+            false
+        }
+    }
+}
+
+fn main() {
+    let mut options = Options::default();
+    let mut command = None;
+    let mut operands = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" | "help" if command.is_none() => {
+                println!("{}", USAGE);
+                return;
+            }
+            "--json" => options.json = true,
+            "--upper" => options.upper = true,
+            "--algorithm" => options.algorithm = args.next(),
+            _ if arg.starts_with("--algorithm=") => {
+                options.algorithm = Some(arg["--algorithm=".len()..].to_string())
+            }
+            "--format" => options.format = args.next(),
+            _ if arg.starts_with("--format=") => {
+                options.format = Some(arg["--format=".len()..].to_string())
+            }
+            _ if arg.starts_with("--") => {
+                eprintln!("fm2gp: unknown option '{}'\n\n{}", arg, USAGE);
+                std::process::exit(2);
+            }
+            _ if command.is_none() => command = Some(arg),
+            _ => operands.push(arg),
+        }
+    }
+    let command = match command {
+        Some(ref command) if COMMANDS.contains(&command.as_str()) => command.clone(),
+        Some(command) => {
+            eprintln!("fm2gp: unknown command '{}'\n\n{}", command, USAGE);
+            std::process::exit(2);
+        }
+        None => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let mut succeeded = true;
+    if operands.is_empty() {
+        let stdin = std::io::stdin();
+        for line in stdin.lock().lines() {
+            let line = line.unwrap_or_else(|e| {
+                eprintln!("fm2gp: {}", e);
+                std::process::exit(1);
+            });
+            let operands: Vec<String> = line.split_whitespace().map(String::from).collect();
+            if !operands.is_empty() {
+                succeeded &= report(&command, &options, &operands);
+            }
+        }
+    } else {
+        succeeded = report(&command, &options, &operands);
+    }
+    if !succeeded {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,125 @@
+// -------------------------------------------------------------------
+// multiple_gcd.rs -- The gcd, lcm and Bezout coefficients of any
+// number of values, folded from the two-argument algorithms of
+// Chapter 12 of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate num_traits;
+extern crate std;
+use ch12::*;
+use std::ops::{Div, Mul, Rem, Sub};
+
+// |a|, so that results do not depend on the signs of the arguments, or
+// None if -a overflows
+fn checked_abs<E>(a: E) -> Option<E>
+where
+    E: PartialOrd + num_traits::Zero + num_traits::CheckedSub,
+{
+    if a < E::zero() {
+        E::zero().checked_sub(&a)
+    } else {
+        Some(a)
+    }
+}
+
+// Folds a two-argument gcd, such as stein_gcd, over values, stopping
+// as soon as the gcd is one; the gcd of no values is zero
+pub fn fold_gcd<E, I, F>(values: I, mut gcd: F) -> E
+where
+    I: IntoIterator<Item = E>,
+    F: FnMut(E, E) -> E,
+    E: num_traits::Zero + num_traits::One + PartialEq,
+{
+    let mut result = E::zero();
+    for v in values {
+        result = gcd(result, v);
+        if result.is_one() {
+            break;
+        }
+    }
+    result
+}
+
+pub fn gcd_all<E, I>(values: I) -> E
+where
+    I: IntoIterator<Item = E>,
+    E: Clone + PartialOrd + num_traits::Zero + num_traits::One,
+    E: num_traits::CheckedSub + for<'a> Rem<&'a E, Output = E>,
+{
+    // precondition: the gcd fits in E, so for the signed primitives some
+    // value is neither zero nor the minimum. A gcd so far that does not,
+    // such as that of the minimum alone, is left negative.
+    let gcd = fold_gcd(values, |a, b| {
+        let d = _gcd(a, b);
+        checked_abs(d.clone()).unwrap_or(d)
+    });
+    checked_abs(gcd).expect("gcd_all: the gcd is out of range")
+}
+
+// The least common multiple of values, or None if it overflows; the
+// lcm of no values is one, and of any list containing zero is zero
+pub fn lcm_all<E, I>(values: I) -> Option<E>
+where
+    I: IntoIterator<Item = E>,
+    E: Clone + PartialOrd + num_traits::Zero + num_traits::One,
+    E: Div<Output = E> + num_traits::CheckedMul + num_traits::CheckedSub,
+    E: for<'a> Rem<&'a E, Output = E>,
+{
+    let mut result = Some(E::one());
+    for v in values {
+        if v.is_zero() {
+            return Some(E::zero());
+        }
+        // once the lcm overflows, only a zero can bring it back in range
+        result = result.and_then(|result| {
+            let v = checked_abs(v)?;
+            // lcm(a, b) = a / gcd(a, b) * b, which overflows only if the
+            // lcm itself does
+            let d = checked_abs(_gcd(result.clone(), v.clone()))?;
+            (result / d).checked_mul(&v)
+        });
+    }
+    result
+}
+
+// Returns (x, gcd) with the sum of x[i] values[i] equal to gcd >= 0.
+// Each step takes the coefficient extended_gcd finds for the gcd so far
+// and solves for the coefficient of the next value; once the gcd is
+// one, the remaining coefficients are zero. The coefficients may grow
+// beyond the values, and overflow for fixed-size integers, as they do
+// for the minimum of a signed primitive.
+pub fn extended_gcd_all<E, I>(values: I) -> (Vec<E>, E)
+where
+    I: IntoIterator<Item = E>,
+    E: Clone + PartialOrd + num_traits::Zero + num_traits::One,
+    E: Sub<Output = E> + for<'a> Mul<&'a E, Output = E>,
+    E: for<'a> Rem<&'a E, Output = E>,
+    for<'a> &'a E: Div<Output = E>,
+{
+    let mut coefficients: Vec<E> = Vec::new();
+    let mut gcd = E::zero();
+    for v in values {
+        if gcd.is_one() {
+            coefficients.push(E::zero());
+            continue;
+        }
+        // x gcd + y v = d
+        let (mut x, mut d) = extended_gcd(gcd.clone(), v.clone());
+        let mut y = if v.is_zero() {
+            E::zero()
+        } else {
+            &(d.clone() - x.clone() * &gcd) / &v
+        };
+        if d < E::zero() {
+            x = E::zero() - x;
+            y = E::zero() - y;
+            d = E::zero() - d;
+        }
+        for c in &mut coefficients {
+            *c = c.clone() * &x;
+        }
+        coefficients.push(y);
+        gcd = d;
+    }
+    (coefficients, gcd)
+}
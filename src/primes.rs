@@ -0,0 +1,108 @@
+// -------------------------------------------------------------------
+// primes.rs -- The primes themselves, read off the sieve of Chapter 3
+// of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch03::*;
+use segmented_sieve::*;
+
+// The sieve of the odd numbers 3, 5, 7, ... up to n: entry i stands for
+// 2i + 3.
+fn odd_sieve(n: u64) -> BitVector {
+    let len = if n < 3 { 0 } else { ((n - 1) / 2) as usize };
+    let mut table = BitVector::new(len, false);
+    sift(&mut table);
+    table
+}
+
+// The primes up to and including n, in increasing order
+pub struct PrimesUpTo {
+    table: BitVector,
+    index: usize,
+    two: bool,
+}
+
+pub fn primes_up_to(n: u64) -> PrimesUpTo {
+    PrimesUpTo {
+        table: odd_sieve(n),
+        index: 0,
+        two: n >= 2,
+    }
+}
+
+impl Iterator for PrimesUpTo {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+        while self.index < self.table.len() {
+            let i = self.index;
+            self.index += 1;
+            if self.table.get(i) {
+                return Some(2 * i as u64 + 3);
+            }
+        }
+        None
+    }
+}
+
+// Number of primes not exceeding n
+pub fn prime_pi(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+    1 + odd_sieve(n).count_ones() as u64
+}
+
+// An upper bound for the kth prime: p(k) < k (ln k + ln ln k) for k >= 6
+fn nth_prime_bound(k: usize) -> u64 {
+    if k < 6 {
+        return 13;
+    }
+    let k = k as f64;
+    (k * (k.ln() + k.ln().ln())).ceil() as u64
+}
+
+// The kth prime, counting from nth_prime(1) = 2
+pub fn nth_prime(k: usize) -> u64 {
+    // precondition: k > 0
+    assert!(k > 0, "nth_prime counts from 1");
+    primes_up_to(nth_prime_bound(k)).nth(k - 1).unwrap()
+}
+
+// All the primes from lo on, found by sieving one segment after another.
+// Only the primes up to the square root of the current segment are
+// kept, so memory grows with the square root of the largest prime
+// yielded.
+pub struct Primes {
+    odd_primes: OddPrimeSegments,
+    two: bool,
+}
+
+pub fn primes() -> Primes {
+    primes_from(0)
+}
+
+pub fn primes_from(lo: u64) -> Primes {
+    let start = if lo < 2 { 1 } else { lo | 1 };
+    Primes {
+        odd_primes: odd_prime_segments(start, u64::MAX, SEGMENT_SIZE, Vec::new(), 1),
+        two: lo <= 2,
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+        self.odd_primes.next()
+    }
+}
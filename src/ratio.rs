@@ -0,0 +1,285 @@
+// -------------------------------------------------------------------
+// ratio.rs -- Exact fractions over a Euclidean domain, kept in lowest
+// terms by the gcd of Chapter 12 of fM2GP, with a positive denominator.
+// -------------------------------------------------------------------
+
+extern crate num_traits;
+extern crate std;
+#[cfg(feature = "ch04")]
+use ch04::*;
+use ch12::*;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// What a fraction needs of its numerator and denominator: a remainder
+// for _gcd, division to cancel the gcd, and an order to keep the
+// denominator positive
+pub trait EuclideanDomain
+where
+    Self: Clone + PartialOrd,
+    Self: num_traits::Zero + num_traits::One,
+    Self: Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>,
+    Self: for<'a> Rem<&'a Self, Output = Self>,
+{
+}
+
+impl<E> EuclideanDomain for E
+where
+    E: Clone + PartialOrd,
+    E: num_traits::Zero + num_traits::One,
+    E: Sub<Output = E> + Mul<Output = E> + Div<Output = E>,
+    E: for<'a> Rem<&'a E, Output = E>,
+{
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio<E> {
+    numer: E,
+    denom: E,
+}
+
+impl<E> Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    pub fn new(numer: E, denom: E) -> Ratio<E> {
+        // precondition: denom != 0
+        assert!(!denom.is_zero(), "zero denominator");
+        // the gcd may come out negative for signed E
+        let d = _gcd(numer.clone(), denom.clone());
+        let (mut numer, mut denom) = (numer / d.clone(), denom / d);
+        if denom < E::zero() {
+            numer = E::zero() - numer;
+            denom = E::zero() - denom;
+        }
+        Ratio { numer, denom }
+    }
+
+    pub fn from_integer(n: E) -> Ratio<E> {
+        Ratio {
+            numer: n,
+            denom: E::one(),
+        }
+    }
+
+    pub fn numer(&self) -> &E {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &E {
+        &self.denom
+    }
+}
+
+impl<E> Add for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Output = Ratio<E>;
+
+    fn add(self, other: Ratio<E>) -> Ratio<E> {
+        Ratio::new(
+            self.numer * other.denom.clone() + other.numer * self.denom.clone(),
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl<E> Sub for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Output = Ratio<E>;
+
+    fn sub(self, other: Ratio<E>) -> Ratio<E> {
+        Ratio::new(
+            self.numer * other.denom.clone() - other.numer * self.denom.clone(),
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl<E> Mul for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Output = Ratio<E>;
+
+    fn mul(self, other: Ratio<E>) -> Ratio<E> {
+        Ratio::new(self.numer * other.numer, self.denom * other.denom)
+    }
+}
+
+impl<E> Div for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Output = Ratio<E>;
+
+    fn div(self, other: Ratio<E>) -> Ratio<E> {
+        // precondition: other != 0
+        Ratio::new(self.numer * other.denom, self.denom * other.numer)
+    }
+}
+
+// x - y q, with q the quotient of x / y as E rounds it
+impl<E> Rem for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Output = Ratio<E>;
+
+    fn rem(self, other: Ratio<E>) -> Ratio<E> {
+        // precondition: other != 0
+        let divisor = other.numer * self.denom.clone();
+        Ratio::new(
+            self.numer * other.denom.clone() % &divisor,
+            self.denom * other.denom,
+        )
+    }
+}
+
+// &x * &y and the like, which the power algorithms of Chapter 7 use
+macro_rules! ratio_ref_binop {
+    ($Op:ident, $op:ident) => {
+        impl<E> $Op<&Ratio<E>> for &Ratio<E>
+        where
+            E: EuclideanDomain,
+        {
+            type Output = Ratio<E>;
+
+            fn $op(self, other: &Ratio<E>) -> Ratio<E> {
+                self.clone().$op(other.clone())
+            }
+        }
+    };
+}
+
+ratio_ref_binop!(Add, add);
+ratio_ref_binop!(Sub, sub);
+ratio_ref_binop!(Mul, mul);
+ratio_ref_binop!(Div, div);
+ratio_ref_binop!(Rem, rem);
+
+impl<E> Neg for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Output = Ratio<E>;
+
+    fn neg(self) -> Ratio<E> {
+        Ratio {
+            numer: E::zero() - self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<E> num_traits::Zero for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    fn zero() -> Ratio<E> {
+        Ratio::from_integer(E::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<E> num_traits::One for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    fn one() -> Ratio<E> {
+        Ratio::from_integer(E::one())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseRatioError<Err> {
+    // the numerator or the denominator did not parse
+    Component(Err),
+    ZeroDenominator,
+}
+
+impl<Err> std::fmt::Display for ParseRatioError<Err>
+where
+    Err: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ParseRatioError::Component(ref err) => write!(f, "invalid fraction: {}", err),
+            ParseRatioError::ZeroDenominator => write!(f, "zero denominator"),
+        }
+    }
+}
+
+impl<Err> std::error::Error for ParseRatioError<Err> where Err: std::error::Error {}
+
+// Parses "n/d", or "n" alone, with both in the given radix
+impl<E> num_traits::Num for Ratio<E>
+where
+    E: EuclideanDomain + num_traits::Num,
+{
+    type FromStrRadixErr = ParseRatioError<E::FromStrRadixErr>;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Ratio<E>, Self::FromStrRadixErr> {
+        let parse = |s| E::from_str_radix(s, radix).map_err(ParseRatioError::Component);
+        let mut parts = s.splitn(2, '/');
+        let numer = parse(parts.next().unwrap())?;
+        let denom = match parts.next() {
+            Some(denom) => parse(denom)?,
+            None => E::one(),
+        };
+        if denom.is_zero() {
+            return Err(ParseRatioError::ZeroDenominator);
+        }
+        Ok(Ratio::new(numer, denom))
+    }
+}
+
+#[cfg(feature = "ch04")]
+impl<E> HalvableSegment for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    type Quotient = E;
+
+    fn half(self) -> Ratio<E> {
+        Ratio::new(self.numer, self.denom.clone() + self.denom)
+    }
+}
+
+impl<E> PartialOrd for Ratio<E>
+where
+    E: EuclideanDomain,
+{
+    fn partial_cmp(&self, other: &Ratio<E>) -> Option<std::cmp::Ordering> {
+        // the denominators are positive
+        (self.numer.clone() * other.denom.clone())
+            .partial_cmp(&(other.numer.clone() * self.denom.clone()))
+    }
+}
+
+impl<E> Ord for Ratio<E>
+where
+    E: EuclideanDomain + Ord,
+{
+    fn cmp(&self, other: &Ratio<E>) -> std::cmp::Ordering {
+        (self.numer.clone() * other.denom.clone()).cmp(&(other.numer.clone() * self.denom.clone()))
+    }
+}
+
+impl<E> std::fmt::Display for Ratio<E>
+where
+    E: std::fmt::Display + EuclideanDomain,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.denom.is_one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
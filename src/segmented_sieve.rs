@@ -0,0 +1,211 @@
+// -------------------------------------------------------------------
+// segmented_sieve.rs -- The sieve of Eratosthenes from Chapter 3 of
+// fM2GP, run one cache-sized segment at a time.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch03::*;
+use primes::*;
+
+// Number of odd candidates in a segment; 32K bools fit in the L1 cache
+// of most machines.
+pub const SEGMENT_SIZE: usize = 1 << 15;
+
+// Odd primes p with p * p < n
+pub fn sieving_primes(n: u64) -> Vec<u64> {
+    primes_up_to(n.saturating_sub(1).isqrt()).skip(1).collect()
+}
+
+// Sieves the odd numbers start, start + 2, ..., start + 2 * (n - 1),
+// where n is the length of segment. Like mark_sieve, the multiples of
+// each prime p are crossed out from p * p on, stepping p places (2p in
+// value) at a time.
+pub fn sift_segment<S>(segment: &mut S, start: u64, primes: &[u64])
+where
+    S: SieveStorage + ?Sized,
+{
+    // precondition: odd(start) && primes holds every odd prime p with
+    // p * p <= start + 2 * (segment.len() - 1)
+    segment.fill(true);
+    if segment.is_empty() {
+        return;
+    }
+    if start == 1 {
+        segment.set(0, false);
+    }
+    // the arithmetic stays below last, which may be as large as u64::MAX
+    let last = start + 2 * (segment.len() as u64 - 1);
+    for &p in primes {
+        let square = p * p;
+        if square > last {
+            break;
+        }
+        // the first odd multiple of p in the segment that is not below p * p
+        let first = if square >= start {
+            Some(square)
+        } else {
+            start.checked_add((p - start % p) % p)
+        };
+        let first = first.and_then(|m| {
+            if m % 2 == 0 {
+                m.checked_add(p)
+            } else {
+                Some(m)
+            }
+        });
+        if let Some(m) = first.filter(|&m| m <= last) {
+            mark_sieve(segment, ((m - start) / 2) as usize, p as usize);
+        }
+    }
+}
+
+// Same table as sift, with the segments shared out among threads. The
+// multiples of the sieving primes are crossed out in each segment on its
+// own, so the threads never touch the same flags.
+pub fn sift_parallel<S>(first: &mut S, threads: usize)
+where
+    S: SplitSieveStorage + ?Sized,
+{
+    // precondition: threads > 0
+    assert!(threads > 0, "sift_parallel needs at least one thread");
+    if first.is_empty() {
+        return;
+    }
+    // entry i stands for 2i + 3, as in sift
+    let last = 2 * first.len() as u64 + 1;
+    let primes = sieving_primes(last + 1);
+    let mut segments = Vec::new();
+    let mut start = 3;
+    for segment in first.pieces_mut(SEGMENT_SIZE) {
+        let n = segment.len() as u64;
+        segments.push((start, segment));
+        start += 2 * n;
+    }
+    let per_thread = segments.len().div_ceil(threads);
+    let primes = &primes;
+    std::thread::scope(|scope| {
+        for group in segments.chunks_mut(per_thread) {
+            scope.spawn(move || {
+                for (start, segment) in group {
+                    sift_segment(segment, *start, primes);
+                }
+            });
+        }
+    });
+}
+
+// The odd primes from start through last, in increasing order. Each
+// segment is sifted when the walk reaches it, with the odd primes up to
+// root; root grows with the square root of the segment's last number,
+// so the primes passed in can be as few as none.
+pub struct OddPrimeSegments {
+    sieving_primes: Vec<u64>,
+    root: u64,
+    segment: Vec<bool>,
+    segment_size: usize,
+    // value of segment[0] and the next index to look at
+    start: Option<u64>,
+    index: usize,
+    last: u64,
+}
+
+pub fn odd_prime_segments(
+    start: u64,
+    last: u64,
+    segment_size: usize,
+    sieving_primes: Vec<u64>,
+    root: u64,
+) -> OddPrimeSegments {
+    // precondition: odd(start) && segment_size > 0 && sieving_primes holds
+    // every odd prime up to root
+    OddPrimeSegments {
+        sieving_primes,
+        root,
+        segment: Vec::new(),
+        segment_size,
+        start: if start <= last { Some(start) } else { None },
+        index: 0,
+        last,
+    }
+}
+
+impl Iterator for OddPrimeSegments {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let start = self.start?;
+            while self.index < self.segment.len() {
+                let i = self.index;
+                self.index += 1;
+                if self.segment[i] {
+                    return Some(start + 2 * i as u64);
+                }
+            }
+            // move on to the next segment
+            let last = self.last;
+            let start = match start
+                .checked_add(2 * self.segment.len() as u64)
+                .filter(|&start| start <= last)
+            {
+                Some(start) => start,
+                None => {
+                    self.start = None;
+                    return None;
+                }
+            };
+            let odd_numbers_left = (last - start) / 2 + 1;
+            let n = std::cmp::min(self.segment_size as u64, odd_numbers_left) as usize;
+            let segment_last = start + 2 * (n as u64 - 1);
+            if self.root < segment_last.isqrt() {
+                self.root = std::cmp::max(segment_last.isqrt(), 2 * self.root);
+                self.sieving_primes = primes_up_to(self.root).skip(1).collect();
+            }
+            self.segment.resize(n, false);
+            sift_segment(&mut self.segment, start, &self.sieving_primes);
+            self.start = Some(start);
+            self.index = 0;
+        }
+    }
+}
+
+// The primes in [lo, hi), in increasing order
+pub struct SegmentedPrimes {
+    odd_primes: OddPrimeSegments,
+    two: bool,
+}
+
+pub fn segmented_primes(lo: u64, hi: u64) -> SegmentedPrimes {
+    segmented_primes_with_segment_size(lo, hi, SEGMENT_SIZE)
+}
+
+pub fn segmented_primes_with_segment_size(
+    lo: u64,
+    hi: u64,
+    segment_size: usize,
+) -> SegmentedPrimes {
+    // precondition: segment_size > 0
+    let start = if lo < 2 { 1 } else { lo | 1 };
+    // the sieving primes for the whole range, found once
+    let (primes, root) = if hi > start {
+        (sieving_primes(hi), (hi - 1).isqrt())
+    } else {
+        (Vec::new(), 1)
+    };
+    SegmentedPrimes {
+        odd_primes: odd_prime_segments(start, hi.saturating_sub(1), segment_size, primes, root),
+        two: lo <= 2 && 2 < hi,
+    }
+}
+
+impl Iterator for SegmentedPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+        self.odd_primes.next()
+    }
+}
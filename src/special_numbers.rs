@@ -0,0 +1,195 @@
+// -------------------------------------------------------------------
+// special_numbers.rs -- The number families of Chapter 3 of fM2GP:
+// figurate numbers, perfect numbers, amicable pairs and Mersenne primes.
+// -------------------------------------------------------------------
+
+extern crate num_integer;
+extern crate num_traits;
+extern crate std;
+use self::num_integer::Roots;
+use factor_sieve::*;
+use primes::*;
+
+// Section 3.1: figurate numbers
+
+// n (n + 1) / 2, dividing the even factor first
+pub fn triangular<I>(n: I) -> I
+where
+    I: num_integer::Integer + Clone,
+{
+    let two = I::one() + I::one();
+    let next = n.clone() + I::one();
+    if n.is_even() {
+        n / two * next
+    } else {
+        n * (next / two)
+    }
+}
+
+// n (n + 1)
+pub fn oblong<I>(n: I) -> I
+where
+    I: num_integer::Integer + Clone,
+{
+    n.clone() * (n + I::one())
+}
+
+// n (3n - 1) / 2, dividing the even factor first
+pub fn pentagonal<I>(n: I) -> I
+where
+    I: num_integer::Integer + Clone,
+{
+    if n.is_zero() {
+        return n;
+    }
+    let two = I::one() + I::one();
+    let three = two.clone() + I::one();
+    let other = three * n.clone() - I::one();
+    if n.is_even() {
+        n / two * other
+    } else {
+        n * (other / two)
+    }
+}
+
+// The index of a figurate number m is either index(m) or index(m) + 1;
+// no figurate number is negative, and the square roots index takes
+// would panic on one
+fn is_figurate<I, K, F>(m: &I, index: K, f: F) -> bool
+where
+    I: num_integer::Integer + Clone,
+    K: Fn(&I) -> I,
+    F: Fn(I) -> I,
+{
+    if *m < I::zero() {
+        return false;
+    }
+    let k = index(m);
+    f(k.clone()) == *m || f(k + I::one()) == *m
+}
+
+pub fn is_triangular<I>(m: &I) -> bool
+where
+    I: num_integer::Integer + Roots + Clone,
+{
+    // precondition: 2m does not overflow
+    let two = I::one() + I::one();
+    is_figurate(m, |m| (two.clone() * m.clone()).sqrt(), triangular)
+}
+
+pub fn is_oblong<I>(m: &I) -> bool
+where
+    I: num_integer::Integer + Roots + Clone,
+{
+    is_figurate(m, |m| m.sqrt(), oblong)
+}
+
+pub fn is_pentagonal<I>(m: &I) -> bool
+where
+    I: num_integer::Integer + Roots + Clone,
+{
+    // precondition: 2m does not overflow
+    let two = I::one() + I::one();
+    let three = two.clone() + I::one();
+    is_figurate(
+        m,
+        |m| (two.clone() * m.clone() / three.clone()).sqrt(),
+        pentagonal,
+    )
+}
+
+// f(1), f(2), f(3), ...
+pub fn figurate_numbers<I, F>(f: F) -> impl Iterator<Item = I>
+where
+    I: num_integer::Integer + Clone,
+    F: Fn(I) -> I,
+{
+    std::iter::successors(Some(I::one()), |n| Some(n.clone() + I::one())).map(f)
+}
+
+// Section 3.2: perfect numbers and amicable pairs
+
+// Sum of the proper divisors of m
+pub fn aliquot_sum(factors: &SmallestFactors, m: u32) -> u64 {
+    // precondition: 0 < m <= factors.limit()
+    factors.sigma(m) - u64::from(m)
+}
+
+pub fn is_perfect(factors: &SmallestFactors, m: u32) -> bool {
+    // precondition: 0 < m <= factors.limit()
+    aliquot_sum(factors, m) == u64::from(m)
+}
+
+pub fn perfect_numbers_up_to(n: u32) -> Vec<u32> {
+    let factors = SmallestFactors::new(n);
+    (1..=n).filter(|&m| is_perfect(&factors, m)).collect()
+}
+
+// The pairs a < b <= n where each is the aliquot sum of the other
+pub fn amicable_pairs_up_to(n: u32) -> Vec<(u32, u32)> {
+    let factors = SmallestFactors::new(n);
+    (1..=n)
+        .filter_map(|a| {
+            let b = aliquot_sum(&factors, a);
+            if u64::from(a) < b
+                && b <= u64::from(n)
+                && aliquot_sum(&factors, b as u32) == u64::from(a)
+            {
+                Some((a, b as u32))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Mersenne primes and Euclid's perfect numbers
+
+// 2^p - 1
+pub fn mersenne<I>(p: u32) -> I
+where
+    I: num_integer::Integer + Clone,
+{
+    num_traits::pow(I::one() + I::one(), p as usize) - I::one()
+}
+
+// Lucas-Lehmer: for an odd prime p, 2^p - 1 is prime if and only if
+// s(p - 2) = 0 (mod 2^p - 1), where s(0) = 4 and s(i + 1) = s(i)^2 - 2
+pub fn is_mersenne_prime(p: u32) -> bool {
+    // precondition: p <= 64, so that the squares fit in a u128
+    assert!(p <= 64, "is_mersenne_prime works up to 2^64 - 1");
+    if !SmallestFactors::new(64).is_prime(p) {
+        return false;
+    }
+    if p == 2 {
+        return true;
+    }
+    let m = mersenne::<u128>(p);
+    let mut s = 4;
+    for _ in 0..p - 2 {
+        s = (s * s + m - 2) % m;
+    }
+    s == 0
+}
+
+// Euclid, Elements IX.36: 2^(p-1) (2^p - 1) is perfect when 2^p - 1 is
+// prime
+pub fn euclid_perfect<I>(p: u32) -> I
+where
+    I: num_integer::Integer + Clone,
+{
+    // precondition: p > 0
+    num_traits::pow(I::one() + I::one(), p as usize - 1) * mersenne(p)
+}
+
+// The primes p <= 64 with 2^p - 1 prime
+pub fn mersenne_exponents() -> impl Iterator<Item = u32> {
+    primes_up_to(64)
+        .map(|p| p as u32)
+        .filter(|&p| is_mersenne_prime(p))
+}
+
+// The even perfect numbers that fit in a u128
+pub fn euclid_perfect_numbers() -> impl Iterator<Item = u128> {
+    mersenne_exponents().map(euclid_perfect)
+}
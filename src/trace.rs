@@ -0,0 +1,187 @@
+// -------------------------------------------------------------------
+// trace.rs -- Step-by-step traces of the Russian peasant algorithm from
+// Chapter 2 of fM2GP.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch02::*;
+
+// One row of the halving and doubling table: n and a as they stand at
+// this step, whether a is added into the accumulator, and the
+// accumulator r after the step. multiply4 has no accumulator until the
+// first odd n, so r is None in the rows before it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceRow<A, N> {
+    pub n: N,
+    pub a: A,
+    pub added: bool,
+    pub r: Option<A>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace<A, N> {
+    pub rows: Vec<TraceRow<A, N>>,
+    pub result: A,
+}
+
+pub fn mult_acc4_trace<A, N>(mut r: A, mut n: N, mut a: A) -> Trace<A, N>
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    // precondition: n > 0
+    let mut rows = Vec::new();
+    loop {
+        let added = n.odd();
+        if added {
+            r += a.clone();
+        }
+        rows.push(TraceRow {
+            n: n.clone(),
+            a: a.clone(),
+            added,
+            r: Some(r.clone()),
+        });
+        if added && n == N::one() {
+            return Trace { rows, result: r };
+        }
+        n = n.half();
+        a += a.clone();
+    }
+}
+
+pub fn multiply4_trace<A, N>(mut n: N, mut a: A) -> Trace<A, N>
+where
+    A: Clone,
+    A: std::ops::Add<Output = A>,
+    A: std::ops::AddAssign,
+    N: Integer,
+{
+    // precondition: n > 0
+    let mut rows = Vec::new();
+    while !n.odd() {
+        rows.push(TraceRow {
+            n: n.clone(),
+            a: a.clone(),
+            added: false,
+            r: None,
+        });
+        a += a.clone();
+        n = n.half();
+    }
+    rows.push(TraceRow {
+        n: n.clone(),
+        a: a.clone(),
+        added: true,
+        r: Some(a.clone()),
+    });
+    if n == N::one() {
+        return Trace { rows, result: a };
+    }
+    // even(n - 1) => n - 1 != 1
+    let rest = mult_acc4_trace(a.clone(), (n - N::one()).half(), a.clone() + a);
+    rows.extend(rest.rows);
+    Trace {
+        rows,
+        result: rest.result,
+    }
+}
+
+impl<A, N> Trace<A, N>
+where
+    A: std::fmt::Display,
+    N: std::fmt::Display,
+{
+    fn cells(&self) -> Vec<[String; 3]> {
+        self.rows
+            .iter()
+            .map(|row| {
+                [
+                    row.n.to_string(),
+                    row.a.to_string(),
+                    row.r.as_ref().map_or(String::new(), |r| r.to_string()),
+                ]
+            })
+            .collect()
+    }
+
+    // Right-aligned columns n, a and r; rows that add a into r are
+    // marked with a '+'.
+    pub fn to_text(&self) -> String {
+        let header = [String::from("n"), String::from("a"), String::from("r")];
+        let cells = self.cells();
+        let mut widths = [0; 3];
+        for row in cells.iter().chain(std::iter::once(&header)) {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = std::cmp::max(*width, cell.len());
+            }
+        }
+        let line = |mark: &str, row: &[String; 3]| {
+            format!(
+                "{} {:>w0$}  {:>w1$}  {:>w2$}\n",
+                mark,
+                row[0],
+                row[1],
+                row[2],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2]
+            )
+        };
+        let mut text = line(" ", &header);
+        for (row, cell) in self.rows.iter().zip(cells.iter()) {
+            text += &line(if row.added { "+" } else { " " }, cell);
+        }
+        text + &format!("result = {}\n", self.result)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut text = String::from("| n | a | added | r |\n|--:|--:|:-:|--:|\n");
+        for (row, cell) in self.rows.iter().zip(self.cells()) {
+            text += &format!(
+                "| {} | {} | {} | {} |\n",
+                cell[0],
+                cell[1],
+                if row.added { "+" } else { "" },
+                cell[2]
+            );
+        }
+        text + &format!("\n**result = {}**\n", self.result)
+    }
+
+    // Values are written with Display, so they are JSON numbers for the
+    // primitive integer types.
+    pub fn to_json(&self) -> String {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .zip(self.cells())
+            .map(|(row, cell)| {
+                format!(
+                    "{{\"n\": {}, \"a\": {}, \"added\": {}, \"r\": {}}}",
+                    cell[0],
+                    cell[1],
+                    row.added,
+                    if row.r.is_some() { &cell[2] } else { "null" }
+                )
+            })
+            .collect();
+        format!(
+            "{{\"rows\": [{}], \"result\": {}}}",
+            rows.join(", "),
+            self.result
+        )
+    }
+}
+
+impl<A, N> std::fmt::Display for Trace<A, N>
+where
+    A: std::fmt::Display,
+    N: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
@@ -0,0 +1,140 @@
+// -------------------------------------------------------------------
+// wheel_sieve.rs -- The sieve of Chapter 3 of fM2GP on a wheel: besides
+// the even numbers, the multiples of 3 and 5 (and 7) are left out of
+// the table as well.
+// -------------------------------------------------------------------
+
+extern crate std;
+use ch03::*;
+
+// The numbers coprime to the product of a few small primes. Entry i of a
+// wheel sieve stands for value(i); entry 0 stands for 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Wheel {
+    primes: Vec<u64>,
+    modulus: u64,
+    // the residues coprime to modulus, 1 first
+    residues: Vec<u64>,
+}
+
+impl Wheel {
+    pub fn new(primes: &[u64]) -> Wheel {
+        let modulus = primes.iter().product();
+        Wheel {
+            primes: primes.to_vec(),
+            modulus,
+            residues: (1..modulus)
+                .filter(|r| primes.iter().all(|p| r % p != 0))
+                .collect(),
+        }
+    }
+
+    // The primes the wheel leaves out
+    pub fn primes(&self) -> &[u64] {
+        &self.primes
+    }
+
+    // Number of table entries per turn of the wheel
+    pub fn spokes(&self) -> usize {
+        self.residues.len()
+    }
+
+    pub fn value(&self, i: usize) -> u64 {
+        let k = self.spokes();
+        self.modulus * (i / k) as u64 + self.residues[i % k]
+    }
+
+    pub fn index(&self, value: u64) -> usize {
+        // precondition: value is coprime to the modulus
+        let k = self.spokes();
+        let spoke = self
+            .residues
+            .binary_search(&(value % self.modulus))
+            .unwrap();
+        (value / self.modulus) as usize * k + spoke
+    }
+
+    // Length of the table holding the candidates up to n
+    pub fn table_len(&self, n: u64) -> usize {
+        let k = self.spokes();
+        let turns = (n / self.modulus) as usize;
+        let spokes = self
+            .residues
+            .iter()
+            .take_while(|&&r| r <= n % self.modulus)
+            .count();
+        turns * k + spokes
+    }
+
+    // The primes up to n, given a table of length table_len(n) sifted
+    // with this wheel
+    pub fn primes_in<S>(&self, table: &S, n: u64) -> Vec<u64>
+    where
+        S: SieveStorage + ?Sized,
+    {
+        let mut primes: Vec<u64> = self.primes.iter().cloned().filter(|&p| p <= n).collect();
+        primes.extend(
+            (0..table.len())
+                .filter(|&i| table.get(i))
+                .map(|i| self.value(i)),
+        );
+        primes
+    }
+}
+
+pub fn wheel30() -> Wheel {
+    Wheel::new(&[2, 3, 5])
+}
+
+pub fn wheel210() -> Wheel {
+    Wheel::new(&[2, 3, 5, 7])
+}
+
+// As in sift, the multiples of each prime factor are crossed out from
+// factor^2 on. The multiples factor * q of the values q coprime to the
+// modulus fall into one arithmetic progression per spoke, each with step
+// factor * spokes in the table, so each is a mark_sieve.
+pub fn sift_wheel<S>(first: &mut S, wheel: &Wheel)
+where
+    S: SieveStorage + ?Sized,
+{
+    let n = first.len();
+    first.fill(true);
+    if n == 0 {
+        return;
+    }
+    first.set(0, false); // 1 is not a prime
+    let k = wheel.spokes();
+    let mut i = 1;
+    let mut factor = wheel.value(i);
+    let mut index_square = wheel.index(factor * factor);
+    while index_square < n {
+        // invariant: factor = value(i), index_square = index(factor^2)
+        if first.get(i) {
+            // q runs over value(i), ..., value(i + k - 1), one per spoke
+            for j in i..i + k {
+                let index = wheel.index(factor * wheel.value(j));
+                if index < n {
+                    mark_sieve(first, index, factor as usize * k);
+                }
+            }
+        }
+        i += 1;
+        factor = wheel.value(i);
+        index_square = wheel.index(factor * factor);
+    }
+}
+
+pub fn sift30<S>(first: &mut S)
+where
+    S: SieveStorage + ?Sized,
+{
+    sift_wheel(first, &wheel30());
+}
+
+pub fn sift210<S>(first: &mut S)
+where
+    S: SieveStorage + ?Sized,
+{
+    sift_wheel(first, &wheel210());
+}
@@ -0,0 +1,122 @@
+// -------------------------------------------------------------------
+// addition_chain.rs -- Checks of the addition chains against the known
+// shortest chain lengths and against plain multiplication and powers.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::addition_chain::*;
+use fm2gp::ch07::Matrix2;
+use std::collections::HashSet;
+use std::num::Wrapping;
+
+// l(n), the length of the shortest addition chain for n = 1, ..., 40
+// (OEIS A003313)
+const SHORTEST: [usize; 40] = [
+    0, 1, 2, 2, 3, 3, 4, 3, 4, 4, 5, 4, 5, 5, 5, 4, 5, 5, 6, 5, 6, 6, 6, 5, 6, 6, 6, 6, 7, 6, 7, 5,
+    6, 6, 7, 6, 7, 7, 7, 6,
+];
+
+// Checks that chain is an addition chain for n
+fn check_chain(chain: &AdditionChain, n: u64) {
+    let values = chain.values();
+    assert_eq!(values.len(), chain.len() + 1, "{}: {}", n, chain);
+    assert_eq!(values[0], 1, "{}: {}", n, chain);
+    assert_eq!(*values.last().unwrap(), n, "{}: {}", n, chain);
+    assert_eq!(chain.is_empty(), n == 1);
+    for k in 1..values.len() {
+        let earlier: HashSet<u64> = values[..k].iter().cloned().collect();
+        assert!(
+            earlier
+                .iter()
+                .any(|&v| v < values[k] && earlier.contains(&(values[k] - v))),
+            "{}: {} is not a sum of earlier elements of {}",
+            n,
+            values[k],
+            chain
+        );
+    }
+}
+
+#[test]
+fn optimal_chain_lengths() {
+    for n in 1..=40 {
+        let chain = optimal_chain(n);
+        check_chain(&chain, n);
+        assert_eq!(chain.len(), SHORTEST[n as usize - 1], "l({})", n);
+    }
+}
+
+#[test]
+fn every_chain_ends_at_n() {
+    for n in 1..5000 {
+        let binary = binary_chain(n);
+        check_chain(&binary, n);
+        let bits = (64 - n.leading_zeros()) + n.count_ones() - 2;
+        assert_eq!(binary.len(), bits as usize, "{}", n);
+        let factor = factor_chain(n);
+        check_chain(&factor, n);
+        for width in 1..7 {
+            check_chain(&window_chain(n, width), n);
+        }
+        assert_eq!(window_chain(n, 1), binary, "{}", n);
+        if n > OPTIMAL_CHAIN_LIMIT {
+            let short = short_chain(n);
+            check_chain(&short, n);
+            assert!(short.len() <= binary.len() && short.len() <= factor.len());
+        }
+    }
+}
+
+#[test]
+fn short_chains_are_optimal_up_to_the_limit() {
+    for n in 1..=256 {
+        let short = short_chain(n);
+        check_chain(&short, n);
+        let report = chain_report(n);
+        assert_eq!(report.optimal, Some(short.len()), "{}", n);
+        assert!(short.len() <= report.binary && short.len() <= report.factor);
+        assert!(short.len() <= report.window);
+    }
+    assert_eq!(chain_report(OPTIMAL_CHAIN_LIMIT + 1).optimal, None);
+}
+
+#[test]
+fn multiply_and_power() {
+    for n in 1..300u64 {
+        let chain = short_chain(n);
+        for &a in &[0u64, 1, 2, 7, 1000, 123_456_789] {
+            assert_eq!(chain.multiply(a), a * n, "{} * {}", a, n);
+        }
+        for &a in &[-5i64, -1, 0, 1, 3] {
+            assert_eq!(chain.multiply(a), a * n as i64, "{} * {}", a, n);
+        }
+        for &a in &[0u64, 1, 2, 3, 10, 0xdead_beef] {
+            assert_eq!(
+                chain.power(Wrapping(a)),
+                Wrapping(a.wrapping_pow(n as u32)),
+                "{}^{}",
+                a,
+                n
+            );
+        }
+        for &chain in &[&binary_chain(n), &factor_chain(n), &window_chain(n, 3)] {
+            assert_eq!(chain.multiply(3u64), 3 * n);
+            assert_eq!(
+                chain.power(Wrapping(3u64)),
+                Wrapping(3u64.wrapping_pow(n as u32))
+            );
+        }
+    }
+}
+
+#[test]
+fn power_of_a_noncommutative_element() {
+    let a = Matrix2([[1i64, 1], [0, 1]]);
+    let b = Matrix2([[2i64, 0], [1, 1]]);
+    let x = &a * &b;
+    let mut expected = x.clone();
+    for n in 1..30 {
+        assert_eq!(short_chain(n).power(x.clone()), expected, "x^{}", n);
+        expected = &expected * &x;
+    }
+}
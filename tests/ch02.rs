@@ -0,0 +1,238 @@
+// -------------------------------------------------------------------
+// ch02.rs -- Checks of the Egyptian multiplication and division
+// routines of Chapter 2 against *, / and %.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch02::*;
+use std::convert::TryFrom;
+
+// 0..=255 as far as it fits, and values near the top of the range
+macro_rules! samples {
+    ($t:ty) => {{
+        let max = <$t>::MAX;
+        let mut samples: Vec<$t> = (0..=255u8).filter_map(|x| <$t>::try_from(x).ok()).collect();
+        let small = *samples.last().unwrap();
+        samples.extend(&[max, max - 1, max / 2, max / 2 + 1, max / 3, max / small * 7]);
+        samples
+    }};
+}
+
+// Every multiply and mult_acc agrees with * on all n > 0 and a of the
+// values given whose product fits
+macro_rules! check_multiply {
+    ($t:ty, $values:expr) => {
+        let values: Vec<$t> = $values;
+        for &n in values.iter().filter(|&&n| n > 0) {
+            for &a in &values {
+                let expected = match n.checked_mul(a).and_then(|p| p.checked_add(a)) {
+                    Some(_) => n * a,
+                    None => continue,
+                };
+                assert_eq!(multiply1(n, a), expected, "multiply1({}, {})", n, a);
+                assert_eq!(multiply2(n, a), expected, "multiply2({}, {})", n, a);
+                assert_eq!(multiply3(n, a), expected, "multiply3({}, {})", n, a);
+                assert_eq!(multiply4(n, a), expected, "multiply4({}, {})", n, a);
+                assert_eq!(mult_acc0(a, n, a), expected + a);
+                assert_eq!(mult_acc1(a, n, a), expected + a);
+                assert_eq!(mult_acc2(a, n, a), expected + a);
+                assert_eq!(mult_acc3(a, n, a), expected + a);
+                assert_eq!(mult_acc4(a, n, a), expected + a);
+                if n as u128 <= 255 {
+                    assert_eq!(multiply0(n, a), expected, "multiply0({}, {})", n, a);
+                }
+            }
+        }
+    };
+}
+
+// A value that is not Copy: n * Tally(v) is v repeated n times
+#[derive(Clone, Debug, PartialEq)]
+struct Tally(Vec<u8>);
+
+impl std::ops::Add for Tally {
+    type Output = Tally;
+
+    fn add(mut self, other: Tally) -> Tally {
+        self.0.extend(other.0);
+        self
+    }
+}
+
+impl std::ops::AddAssign for Tally {
+    fn add_assign(&mut self, other: Tally) {
+        self.0.extend(other.0);
+    }
+}
+
+// The checked, wrapping and saturating mult_acc4 and multiply4 agree
+// with r + n * a worked out in i128 and then checked, wrapped or
+// clamped, for every r and a of type $t and every i8 n
+macro_rules! check_overflow_aware {
+    ($t:ty) => {
+        let (min, max) = (i128::from(<$t>::MIN), i128::from(<$t>::MAX));
+        let checked = |x: i128| <$t>::try_from(x).ok();
+        let wrapped = |x: i128| x.rem_euclid(max - min + 1) as $t;
+        let clamped = |x: i128| std::cmp::max(min, std::cmp::min(max, x)) as $t;
+        for n in i8::MIN..=i8::MAX {
+            for a in <$t>::MIN..=<$t>::MAX {
+                let product = i128::from(n) * i128::from(a);
+                assert_eq!(
+                    checked_multiply4(n, a),
+                    checked(product),
+                    "checked_multiply4({}, {})",
+                    n,
+                    a
+                );
+                assert_eq!(
+                    wrapping_multiply4(n, a),
+                    wrapped(product),
+                    "wrapping_multiply4({}, {})",
+                    n,
+                    a
+                );
+                assert_eq!(
+                    saturating_multiply4(n, a),
+                    clamped(product),
+                    "saturating_multiply4({}, {})",
+                    n,
+                    a
+                );
+                for r in <$t>::MIN..=<$t>::MAX {
+                    let exact = i128::from(r) + product;
+                    assert_eq!(
+                        checked_mult_acc4(r, n, a),
+                        checked(exact),
+                        "checked_mult_acc4({}, {}, {})",
+                        r,
+                        n,
+                        a
+                    );
+                    assert_eq!(
+                        wrapping_mult_acc4(r, n, a),
+                        wrapped(exact),
+                        "wrapping_mult_acc4({}, {}, {})",
+                        r,
+                        n,
+                        a
+                    );
+                    assert_eq!(
+                        saturating_mult_acc4(r, n, a),
+                        clamped(exact),
+                        "saturating_mult_acc4({}, {}, {})",
+                        r,
+                        n,
+                        a
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[test]
+fn overflow_aware_i8_exhaustive() {
+    check_overflow_aware!(i8);
+}
+
+#[test]
+fn overflow_aware_u8_exhaustive() {
+    check_overflow_aware!(u8);
+}
+
+#[test]
+fn overflow_aware_in_range_despite_the_product() {
+    // n * a = 200 does not fit in an i8, but r + n * a = 72 does
+    assert_eq!(checked_mult_acc4(-128i8, 100i8, 2i8), Some(72));
+    assert_eq!(saturating_mult_acc4(-128i8, 100i8, 2i8), 72);
+    assert_eq!(checked_mult_acc4(127i8, -1i8, i8::MIN), None);
+    assert_eq!(checked_mult_acc4(-1i8, -1i8, i8::MIN), Some(127));
+    assert_eq!(checked_mult_acc4(200u8, -2i8, 100u8), Some(0));
+    assert_eq!(checked_mult_acc4(5i64, 0, i64::MIN), Some(5));
+    assert_eq!(checked_multiply4(i64::MIN, -1i64), None);
+    assert_eq!(saturating_multiply4(i64::MIN, -1i64), i64::MAX);
+    assert_eq!(wrapping_multiply4(i64::MIN, -1i64), i64::MIN);
+}
+
+#[test]
+fn multiply_8_bits_exhaustive() {
+    check_multiply!(u8, (0..=u8::MAX).collect());
+    check_multiply!(i8, (i8::MIN..=i8::MAX).collect());
+}
+
+#[test]
+fn multiply_wider_samples() {
+    check_multiply!(u16, samples!(u16));
+    check_multiply!(u32, samples!(u32));
+    check_multiply!(u64, samples!(u64));
+    check_multiply!(u128, samples!(u128));
+    check_multiply!(i32, samples!(i32));
+    check_multiply!(i128, samples!(i128));
+}
+
+#[test]
+fn multiply_a_type_that_is_not_copy() {
+    let a = Tally(vec![1, 2]);
+    let expected = Tally([1, 2].repeat(41));
+    assert_eq!(multiply0(41u8, a.clone()), expected);
+    assert_eq!(multiply4(41u128, a.clone()), expected);
+    assert_eq!(mult_acc4(Tally(vec![]), 41i64, a.clone()), expected);
+    assert_eq!(multiply_by_15(a), Tally([1, 2].repeat(15)));
+}
+
+// Every divide agrees with / and % on all pairs of the values given;
+// divide0 subtracts once per unit of the quotient, so it only gets the
+// pairs with small quotients.
+macro_rules! check_divide {
+    ($t:ty, $values:expr) => {
+        let values: Vec<$t> = $values;
+        for &a in &values {
+            for &b in values.iter().filter(|&&b| b > 0) {
+                let expected = (a / b, a % b);
+                assert_eq!(divide1(a, b), expected, "divide1({}, {})", a, b);
+                assert_eq!(divide2(a, b), expected, "divide2({}, {})", a, b);
+                assert_eq!(divide3(a, b), expected, "divide3({}, {})", a, b);
+                if (a / b) as u128 <= 255 {
+                    assert_eq!(divide0(a, b), expected, "divide0({}, {})", a, b);
+                }
+            }
+        }
+    };
+}
+
+#[test]
+fn divide_8_bits_exhaustive() {
+    check_divide!(u8, (0..=u8::MAX).collect());
+    check_divide!(i8, (0..=i8::MAX).collect());
+}
+
+#[test]
+fn divide_wider_samples() {
+    check_divide!(u16, samples!(u16));
+    check_divide!(u32, samples!(u32));
+    check_divide!(u64, samples!(u64));
+    check_divide!(u128, samples!(u128));
+    check_divide!(usize, samples!(usize));
+    check_divide!(i16, samples!(i16));
+    check_divide!(i32, samples!(i32));
+    check_divide!(i64, samples!(i64));
+    check_divide!(i128, samples!(i128));
+    check_divide!(isize, samples!(isize));
+}
+
+#[test]
+fn divide_acc_from_a_doubling() {
+    // 45 = 7 * 6 + 3, and 24 is 6 doubled twice
+    let expected = (7, 3);
+    assert_eq!(divide_acc0(0, 45, 24, 6), expected);
+    assert_eq!(divide_acc1(0, 45, 24, 6), expected);
+    assert_eq!(divide_acc2(0, 45, 24, 6), expected);
+    assert_eq!(divide_acc3(0, 45, 24, 6), expected);
+    assert_eq!(divide_acc4(0, 45, 24, 6), expected);
+}
+
+#[test]
+fn divide_segments() {
+    assert_eq!(divide2(10.0, 0.75), (13.0, 0.25));
+    assert_eq!(divide3(100u32, 7), (14, 2));
+}
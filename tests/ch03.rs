@@ -0,0 +1,65 @@
+// -------------------------------------------------------------------
+// ch03.rs -- Checks of the gcm of Chapter 3 against Euclid's gcd.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch03::*;
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[test]
+fn gcm_matches_gcd() {
+    for a in 1..200u32 {
+        for b in 1..200 {
+            assert_eq!(gcm(a, b), gcd(a, b), "gcm({}, {})", a, b);
+            assert_eq!(gcm(i64::from(a), i64::from(b)), i64::from(gcd(a, b)));
+        }
+    }
+}
+
+#[test]
+fn gcm_measures_floats() {
+    assert_eq!(gcm(2.5, 0.75), 0.25);
+    assert_eq!(gcm(1e6, 0.5), 0.5);
+}
+
+#[test]
+fn gcm_does_not_recurse() {
+    assert_eq!(gcm(1_000_000u32, 1), 1);
+}
+
+#[test]
+#[should_panic]
+fn gcm_rejects_zero() {
+    gcm(0u32, 5);
+}
+
+#[test]
+#[should_panic]
+fn gcm_rejects_negative_segments() {
+    gcm(-3i32, 5);
+}
+
+#[test]
+#[should_panic]
+fn gcm_rejects_infinity() {
+    gcm(f64::INFINITY, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn gcm_rejects_nan() {
+    gcm(f64::NAN, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn gcm_rejects_segments_too_far_apart_to_subtract() {
+    gcm(1e20, 1.0);
+}
@@ -0,0 +1,169 @@
+// -------------------------------------------------------------------
+// ch04.rs -- Checks of the line segment algorithms of Chapter 4.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch04::*;
+use std::ops::{Add, Sub};
+
+fn check_fibonacci_division(a: u16, b: u16) {
+    let expected = quotient_remainder(a, b);
+    assert_eq!(
+        quotient_remainder_fibonacci(a, b),
+        expected,
+        "quotient_remainder_fibonacci({}, {})",
+        a,
+        b
+    );
+    assert_eq!(quotient_fibonacci(a, b), expected.0);
+    assert_eq!(remainder_fibonacci(a, b), expected.1);
+}
+
+#[test]
+fn fibonacci_division_matches_quotient_remainder_on_u8_range() {
+    for a in 0..=u16::from(u8::MAX) {
+        for b in 1..=u16::from(u8::MAX) {
+            check_fibonacci_division(a, b);
+        }
+    }
+}
+
+// Fibonacci numbers, where the walk turns, and their neighbours, and
+// the ends of the range
+fn u16_samples() -> Vec<u16> {
+    vec![
+        1, 2, 3, 4, 7, 8, 89, 144, 145, 4181, 28657, 46367, 46368, 65534, 65535,
+    ]
+}
+
+#[test]
+fn fibonacci_division_matches_quotient_remainder_for_every_u16_dividend() {
+    for b in u16_samples() {
+        for a in 0..=u16::MAX {
+            check_fibonacci_division(a, b);
+        }
+    }
+}
+
+#[test]
+fn fibonacci_division_matches_quotient_remainder_for_every_u16_divisor() {
+    for a in u16_samples().into_iter().chain(vec![0, 10_000, 50_000]) {
+        for b in 1..=u16::MAX {
+            check_fibonacci_division(a, b);
+        }
+    }
+}
+
+// Every pair of u16 segments: about a minute in release, far longer in
+// a debug build, so run it with
+//
+//     cargo test --release --test ch04 -- --ignored
+#[test]
+#[ignore]
+fn fibonacci_division_matches_quotient_remainder_on_u16() {
+    for a in 0..=u16::MAX {
+        for b in 1..=u16::MAX {
+            check_fibonacci_division(a, b);
+        }
+    }
+}
+
+// A segment with a quotient but no halving, which only the Fibonacci
+// division can divide
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Length(u32);
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, other: Length) -> Length {
+        Length(self.0 + other.0)
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, other: Length) -> Length {
+        Length(self.0 - other.0)
+    }
+}
+
+impl MeasurableSegment for Length {
+    type Quotient = u32;
+}
+
+#[test]
+fn fibonacci_division_needs_no_halving() {
+    for a in 0..300 {
+        for b in 1..300 {
+            assert_eq!(
+                quotient_remainder_fibonacci(Length(a), Length(b)),
+                (a / b, Length(a % b))
+            );
+        }
+    }
+}
+
+#[test]
+fn try_division_rejects_what_would_not_terminate() {
+    assert_eq!(
+        try_remainder(f64::INFINITY, 1.0),
+        Err(DivisionError::DoublingOverflow)
+    );
+    assert_eq!(
+        try_quotient(f32::INFINITY, 0.5),
+        Err(DivisionError::DoublingOverflow)
+    );
+    assert_eq!(
+        try_largest_doubling(&f64::INFINITY, 3.0),
+        Err(DivisionError::DoublingOverflow)
+    );
+    assert_eq!(
+        try_remainder(f64::NAN, 1.0),
+        Err(DivisionError::NegativeSegment)
+    );
+    assert_eq!(
+        try_quotient_remainder(1.0, f64::NAN),
+        Err(DivisionError::NegativeSegment)
+    );
+    assert_eq!(try_remainder(1.0, f64::INFINITY), Ok(1.0));
+}
+
+#[test]
+fn try_division_matches_division_on_finite_segments() {
+    assert_eq!(
+        try_quotient_remainder(f64::MAX, 1.0),
+        Ok(quotient_remainder(f64::MAX, 1.0))
+    );
+    assert_eq!(try_remainder(1e300, 1e-300), Ok(remainder(1e300, 1e-300)));
+    for a in 0..=u8::MAX {
+        for b in 1..=u8::MAX {
+            assert_eq!(try_quotient_remainder(a, b), Ok((a / b, a % b)));
+        }
+    }
+}
+
+#[test]
+fn gcm_bounded_gives_up_on_what_cannot_be_measured() {
+    assert_eq!(gcm_bounded(15u32, 9, 100), Ok(3));
+    assert_eq!(gcm_bounded(2.5, 0.75, 100), Ok(0.25));
+    // the side and diagonal of a square
+    assert!(gcm_bounded(2f64.sqrt(), 1.0, 100).is_err());
+    for &(a, b) in &[
+        (f64::NAN, 1.0),
+        (1.0, f64::NAN),
+        (f64::NAN, f64::NAN),
+        (0.0, 1.0),
+        (1.0, 0.0),
+        (f64::INFINITY, 1.0),
+        (1.0, f64::INFINITY),
+        (-1.0, 1.0),
+    ] {
+        assert!(gcm_bounded(a, b, 100).is_err(), "gcm_bounded({}, {})", a, b);
+    }
+    assert_eq!(
+        gcm_bounded(0u32, 5, 100),
+        Err(DidNotConverge { steps: 100 })
+    );
+}
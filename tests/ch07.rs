@@ -0,0 +1,73 @@
+// -------------------------------------------------------------------
+// ch07.rs -- Checks of the matrix power Fibonacci numbers of Chapter 7
+// against the recurrence.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+extern crate num_traits;
+use fm2gp::ch07::*;
+use num_traits::One;
+use std::num::Wrapping;
+
+// F(0), F(1), ..., F(n) by F(k + 2) = F(k + 1) + F(k), in u128
+fn fibonacci_numbers(n: usize) -> Vec<u128> {
+    let mut f = vec![0, 1];
+    while f.len() <= n {
+        let k = f.len();
+        f.push(f[k - 1] + f[k - 2]);
+    }
+    f.truncate(n + 1);
+    f
+}
+
+#[test]
+fn fibonacci_up_to_the_last_that_fits() {
+    let f = fibonacci_numbers(186);
+    // F(46), F(93) and F(186) are the last to fit in i32, u64 and u128
+    assert!(f[46] <= i32::MAX as u128 && f[47] > i32::MAX as u128);
+    assert!(f[93] <= u128::from(u64::MAX) && f[94] > u128::from(u64::MAX));
+    assert!(f[186].checked_add(f[185]).is_none());
+    for n in 0..=46 {
+        assert_eq!(fibonacci::<i32, _>(n) as u128, f[n as usize], "F({})", n);
+    }
+    for n in 0..=93u32 {
+        assert_eq!(
+            u128::from(fibonacci::<u64, _>(n)),
+            f[n as usize],
+            "F({})",
+            n
+        );
+    }
+    for n in 0..=186i64 {
+        assert_eq!(fibonacci::<u128, _>(n), f[n as usize], "F({})", n);
+    }
+}
+
+#[test]
+fn fibonacci_modulo_a_power_of_two() {
+    let (mut a, mut b) = (Wrapping(0u64), Wrapping(1u64));
+    for n in 0..3000u32 {
+        assert_eq!(fibonacci::<Wrapping<u64>, _>(n), a, "F({}) mod 2^64", n);
+        let c = a + b;
+        a = b;
+        b = c;
+    }
+    // the Fibonacci numbers mod 2^8 repeat with period 3 * 2^7
+    for n in 0..1000u64 {
+        assert_eq!(
+            fibonacci::<Wrapping<u8>, _>(n + 384),
+            fibonacci::<Wrapping<u8>, _>(n)
+        );
+    }
+}
+
+#[test]
+fn matrix_product() {
+    let x = Matrix2([[1i64, 2], [3, 4]]);
+    let y = Matrix2([[5i64, -6], [7, 8]]);
+    assert_eq!(&x * &y, Matrix2([[19, 10], [43, 14]]));
+    assert_eq!(Matrix2::one() * x.clone(), x);
+    assert_eq!(x.clone() * Matrix2::one(), x);
+    let z = Matrix2([[0i64, 1], [-1, 2]]);
+    assert_eq!((&x * &y) * z.clone(), x * (&y * &z));
+}
@@ -0,0 +1,35 @@
+// -------------------------------------------------------------------
+// ch11.rs -- Checks of the reversal algorithms of Chapter 11 against
+// slice::reverse.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch11::fmgp::*;
+
+#[test]
+fn reverse_n_adaptive_with_any_buffer() {
+    for len in 0..40 {
+        for f in 0..=len {
+            let n = len - f;
+            for buffer_len in &[0, 1, 3, n / 4, n, n + 1, 2 * len + 10] {
+                let mut v: Vec<i32> = (0..len as i32).collect();
+                let mut expected = v.clone();
+                expected[f..].reverse();
+                let mut buffer = vec![0; *buffer_len];
+                assert_eq!(reverse_n_adaptive(&mut v, f, n, &mut buffer), len);
+                assert_eq!(v, expected, "f = {}, n = {}, buffer {}", f, n, buffer_len);
+            }
+        }
+    }
+}
+
+#[test]
+fn reverse_recursive_matches_reverse() {
+    for len in 0..40 {
+        let mut v: Vec<i32> = (0..len).collect();
+        let mut expected = v.clone();
+        expected.reverse();
+        reverse_recursive(&mut v, 0, len as usize);
+        assert_eq!(v, expected);
+    }
+}
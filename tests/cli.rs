@@ -0,0 +1,100 @@
+// -------------------------------------------------------------------
+// cli.rs -- Checks of the fm2gp command-line tool on bad input.
+// -------------------------------------------------------------------
+
+use std::process::Command;
+
+// The exit status and the standard output or error of fm2gp args
+fn fm2gp(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_fm2gp"))
+        .args(args)
+        .output()
+        .unwrap();
+    let text = if output.status.success() {
+        output.stdout
+    } else {
+        output.stderr
+    };
+    (output.status.success(), String::from_utf8(text).unwrap())
+}
+
+#[test]
+fn miller_rabin_rejects_witnesses_outside_the_range() {
+    for args in &[["miller-rabin", "9", "9"], ["miller-rabin", "9", "1"]] {
+        let (ok, text) = fm2gp(args);
+        assert!(!ok, "{:?} gave {}", args, text);
+        assert!(text.contains("[2, 7]"), "{}", text);
+    }
+    assert_eq!(fm2gp(&["miller-rabin", "9"]), (true, "false\n".to_string()));
+    // 2047 = 23 * 89 is a strong pseudoprime to base 2
+    assert_eq!(
+        fm2gp(&["miller-rabin", "2047", "2"]),
+        (true, "true\n".to_string())
+    );
+    assert_eq!(
+        fm2gp(&["miller-rabin", "2047"]),
+        (true, "false\n".to_string())
+    );
+}
+
+#[test]
+fn is_prime_near_the_top_of_u64() {
+    // the largest 64-bit prime, and the odd number after it
+    assert_eq!(
+        fm2gp(&["is-prime", "18446744073709551557"]),
+        (true, "true\n".to_string())
+    );
+    assert_eq!(
+        fm2gp(&["is-prime", "18446744073709551559"]),
+        (true, "false\n".to_string())
+    );
+}
+
+#[test]
+fn stein_gcd_rejects_the_minimum_i64() {
+    let (ok, _) = fm2gp(&["gcd", "--algorithm", "stein", "-9223372036854775808", "0"]);
+    assert!(!ok);
+    assert_eq!(
+        fm2gp(&["gcd", "--algorithm", "stein", "-9223372036854775807", "0"]),
+        (true, "9223372036854775807\n".to_string())
+    );
+}
+
+#[test]
+fn gcd_stats_rejects_an_empty_range() {
+    let (ok, text) = fm2gp(&["gcd-stats", "5", "3"]);
+    assert!(!ok);
+    assert!(text.contains("empty range"), "{}", text);
+    let (ok, _) = fm2gp(&["gcd-stats", "0", "3"]);
+    assert!(!ok);
+    assert!(fm2gp(&["gcd-stats", "3", "3"]).0);
+}
+
+#[test]
+fn egcd_gives_a_nonnegative_gcd_for_any_i64() {
+    for &(a, b) in &[
+        (4i128, -6i128),
+        (-4, 6),
+        (-4, -6),
+        (240, 46),
+        (0, -5),
+        (0, 0),
+        (i64::MAX.into(), i64::MIN.into()),
+        (i64::MIN.into(), i64::MIN.into()),
+        (i64::MIN.into(), 0),
+    ] {
+        let (ok, text) = fm2gp(&["egcd", &a.to_string(), &b.to_string()]);
+        assert!(ok, "egcd {} {}: {}", a, b, text);
+        let values: Vec<i128> = text
+            .split_whitespace()
+            .map(|v| v.parse().unwrap())
+            .collect();
+        let (gcd, x, y) = (values[0], values[1], values[2]);
+        assert!(gcd >= 0, "egcd {} {} = {}", a, b, text);
+        assert_eq!(a * x + b * y, gcd, "egcd {} {} = {}", a, b, text);
+        if gcd != 0 {
+            assert_eq!((a % gcd, b % gcd), (0, 0));
+        }
+    }
+    assert_eq!(fm2gp(&["egcd", "4", "-6"]), (true, "2 -1 -1\n".to_string()));
+}
@@ -0,0 +1,120 @@
+// -------------------------------------------------------------------
+// continued_fraction.rs -- Checks of the continued fractions of a / b
+// against Euclid's algorithm and a search over every denominator.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch12::*;
+use fm2gp::continued_fraction::*;
+use fm2gp::ratio::*;
+
+// The quotients of Euclid's algorithm, by the built-in division
+fn euclid_quotients(mut a: i64, mut b: i64) -> Vec<i64> {
+    let mut terms = Vec::new();
+    while b != 0 {
+        terms.push(a / b);
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    terms
+}
+
+// The value of [q0; q1, ..., qn], from the last term back
+fn evaluate(terms: &[i64]) -> Ratio<i64> {
+    let (last, rest) = terms.split_last().unwrap();
+    rest.iter().rev().fold(Ratio::from_integer(*last), |x, &q| {
+        Ratio::from_integer(q) + Ratio::from_integer(1) / x
+    })
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[test]
+fn continued_fraction_matches_euclid() {
+    for a in 0..200 {
+        for b in 1..200 {
+            let terms = continued_fraction(a, b);
+            assert_eq!(terms, euclid_quotients(a, b), "{} / {}", a, b);
+            assert_eq!(evaluate(&terms), Ratio::new(a, b), "{} / {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn convergents_end_at_the_fraction() {
+    for a in 0..200 {
+        for b in 1..200 {
+            let terms = continued_fraction(a, b);
+            let convergents = convergents(&terms);
+            assert_eq!(convergents.len(), terms.len());
+            for k in 0..terms.len() {
+                assert_eq!(convergents[k], evaluate(&terms[..=k]), "{} / {}", a, b);
+            }
+            assert_eq!(*convergents.last().unwrap(), Ratio::new(a, b));
+        }
+    }
+}
+
+// |a / b - p / q|, scaled by b q
+fn scaled_distance(a: i64, b: i64, p: i64, q: i64) -> i64 {
+    (a * q - b * p).abs()
+}
+
+#[test]
+fn best_approximation_matches_search() {
+    for a in 0..200 {
+        for b in 1..200 {
+            for max_denom in 1..30 {
+                let best = best_approximation(a, b, max_denom);
+                let (p, q) = (*best.numer(), *best.denom());
+                assert!(0 < q && q <= max_denom);
+                // the closest p / q for each q is p = floor(a q / b) or
+                // one more; the distances are compared as fractions
+                let closest = (1..=max_denom)
+                    .flat_map(|q| {
+                        let p = a * q / b;
+                        vec![(p, q), (p + 1, q)]
+                    })
+                    .map(|(p, q)| Ratio::new(scaled_distance(a, b, p, q), b * q))
+                    .min()
+                    .unwrap();
+                assert_eq!(
+                    Ratio::new(scaled_distance(a, b, p, q), b * q),
+                    closest,
+                    "best_approximation({}, {}, {}) = {}",
+                    a,
+                    b,
+                    max_denom,
+                    best
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn bezout_coefficient_matches_extended_gcd() {
+    for a in 0..200 {
+        for b in 1..200 {
+            let (x, d) = bezout_coefficient(a, b);
+            let g = gcd(a, b);
+            assert_eq!(d, g, "bezout_coefficient({}, {})", a, b);
+            let m = b / g;
+            assert!(0 <= x && x < m, "bezout_coefficient({}, {}) = {}", a, b, x);
+            assert_eq!((x * a - d) % b, 0, "bezout_coefficient({}, {})", a, b);
+            // extended_gcd finds the same coefficient, up to sign and
+            // multiples of b / gcd
+            let (y, e) = extended_gcd(a, b);
+            let (y, e) = if e < 0 { (-y, -e) } else { (y, e) };
+            assert_eq!(e, d);
+            assert_eq!((y % m + m) % m, x, "extended_gcd({}, {})", a, b);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// -------------------------------------------------------------------
+// counted.rs -- Checks of the operation counts of Counted values.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch02::{multiply0, multiply4};
+use fm2gp::ch12::stein_gcd;
+use fm2gp::counted::*;
+
+#[test]
+fn multiply0_adds_and_subtracts_once_per_unit_of_n() {
+    for n in 1..100u32 {
+        let (product, counts) = count_operations(|| multiply0(Counted(n), Counted(59u32)));
+        assert_eq!(product.0, n * 59);
+        assert_eq!(counts.additions, u64::from(n) - 1);
+        assert_eq!(counts.subtractions, u64::from(n) - 1);
+    }
+}
+
+#[test]
+fn multiply4_halves_n_once_per_bit() {
+    for n in 1..1000u64 {
+        let (product, counts) = count_operations(|| multiply4(Counted(n), Counted(59u64)));
+        assert_eq!(product.0, n * 59);
+        let bits = 64 - u64::from(n.leading_zeros());
+        assert_eq!(counts.shifts, bits - 1);
+        // one doubling per halving, and one addition per further one bit
+        assert_eq!(counts.additions, bits + u64::from(n.count_ones()) - 2);
+    }
+}
+
+#[test]
+fn counted_128_bit_values() {
+    let (product, counts) = count_operations(|| multiply4(Counted(1u128 << 100), Counted(3i128)));
+    assert_eq!(product.0, 3 << 100);
+    assert_eq!(counts.additions, 100);
+    let (gcd, _) = count_operations(|| stein_gcd(Counted(-(6i128 << 90)), Counted(4i128 << 90)));
+    assert_eq!(gcd.0, 2 << 90);
+}
@@ -0,0 +1,114 @@
+// -------------------------------------------------------------------
+// factor_sieve.rs -- Checks of the smallest factor sieve and what is
+// read off it against trial division.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::factor_sieve::*;
+
+const LIMIT: u32 = 5000;
+
+fn trial_smallest_factor(m: u32) -> u32 {
+    (2..m)
+        .take_while(|d| d * d <= m)
+        .find(|&d| m.is_multiple_of(d))
+        .unwrap_or(m)
+}
+
+fn trial_divisors(m: u32) -> Vec<u32> {
+    (1..=m).filter(|&d| m.is_multiple_of(d)).collect()
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[test]
+fn smallest_factors_match_trial_division() {
+    let factors = SmallestFactors::new(LIMIT);
+    assert_eq!(factors.limit(), LIMIT);
+    assert_eq!(factors.smallest_factor(0), 0);
+    assert_eq!(factors.smallest_factor(1), 1);
+    assert!(!factors.is_prime(0) && !factors.is_prime(1));
+    for m in 2..=LIMIT {
+        let p = trial_smallest_factor(m);
+        assert_eq!(factors.smallest_factor(m), p, "m = {}", m);
+        assert_eq!(factors.is_prime(m), p == m, "m = {}", m);
+    }
+}
+
+#[test]
+fn small_tables() {
+    for n in 0..20 {
+        let factors = SmallestFactors::new(n);
+        assert_eq!(factors.limit(), n);
+        for m in 2..=n {
+            assert_eq!(factors.smallest_factor(m), trial_smallest_factor(m));
+        }
+    }
+}
+
+#[test]
+fn factorizations_match_trial_division() {
+    let factors = SmallestFactors::new(LIMIT);
+    assert_eq!(factors.factorize(1), vec![]);
+    for m in 1..=LIMIT {
+        let factorization = factors.factorize(m);
+        // the primes increase, and their powers multiply back to m
+        assert!(
+            factorization.windows(2).all(|w| w[0].0 < w[1].0),
+            "m = {}",
+            m
+        );
+        assert!(factorization
+            .iter()
+            .all(|&(p, e)| e > 0 && trial_smallest_factor(p) == p));
+        let product: u32 = factorization.iter().map(|&(p, e)| p.pow(e)).product();
+        assert_eq!(product, m);
+
+        let divisors = trial_divisors(m);
+        assert_eq!(factors.divisors(m), divisors, "m = {}", m);
+        assert_eq!(factors.tau(m) as usize, divisors.len(), "m = {}", m);
+        assert_eq!(
+            factors.sigma(m),
+            divisors.iter().map(|&d| u64::from(d)).sum::<u64>(),
+            "m = {}",
+            m
+        );
+        let coprime = (1..=m).filter(|&k| gcd(k, m) == 1).count();
+        assert_eq!(factors.euler_phi(m) as usize, coprime, "m = {}", m);
+    }
+}
+
+#[test]
+fn prime_powers() {
+    let factors = SmallestFactors::new(1 << 20);
+    for &p in &[2u32, 3, 5, 7, 31] {
+        let mut power = 1;
+        for e in 1.. {
+            power *= p;
+            if power > factors.limit() {
+                break;
+            }
+            assert_eq!(factors.factorize(power), vec![(p, e)]);
+            assert_eq!(
+                factors.divisors(power),
+                (0..=e).map(|i| p.pow(i)).collect::<Vec<u32>>()
+            );
+            assert_eq!(factors.tau(power), e + 1);
+            assert_eq!(factors.sigma(power), u64::from((power * p - 1) / (p - 1)));
+            assert_eq!(factors.euler_phi(power), power - power / p);
+        }
+    }
+    assert_eq!(factors.factorize(1 << 20), vec![(2, 20)]);
+}
+
+#[test]
+#[should_panic]
+fn zero_has_no_factorization() {
+    SmallestFactors::new(10).factorize(0);
+}
@@ -0,0 +1,48 @@
+// -------------------------------------------------------------------
+// gcd_statistics.rs -- Checks of the gcd statistics and of Lamé's bound.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::gcd_statistics::*;
+
+#[test]
+fn empty_and_nonpositive_ranges_are_errors() {
+    assert_eq!(gcd_statistics(5, 3), Err(RangeError::Empty));
+    assert_eq!(lame_check(5, 3), Err(RangeError::Empty));
+    assert_eq!(gcd_statistics(0, 3), Err(RangeError::NotPositive));
+    assert_eq!(lame_check(-3, 3), Err(RangeError::NotPositive));
+}
+
+#[test]
+fn statistics_cover_every_algorithm_and_pair() {
+    let statistics = gcd_statistics(3, 3).unwrap();
+    let algorithms: Vec<GcdAlgorithm> = statistics.iter().map(|s| s.algorithm).collect();
+    assert_eq!(algorithms, ALGORITHMS);
+    assert!(statistics
+        .iter()
+        .all(|s| s.pairs == 1 && s.worst_case == (3, 3)));
+
+    // 1 + 2 + ... + 10 pairs
+    let statistics = gcd_statistics(1, 10).unwrap();
+    assert!(statistics.iter().all(|s| s.pairs == 55));
+}
+
+#[test]
+fn lame_bound_holds_with_fibonacci_worst_cases() {
+    let check = lame_check(1, 300).unwrap();
+    assert!(check.holds());
+    assert_eq!(check.pairs, 300 * 301 / 2);
+    // 233 and 144 are the largest consecutive Fibonacci numbers below 300
+    assert_eq!(check.worst_case, (233, 144));
+    assert!(is_consecutive_fibonacci(233, 144));
+}
+
+#[test]
+fn algorithm_names_are_distinct() {
+    for (i, a) in ALGORITHMS.iter().enumerate() {
+        for b in &ALGORITHMS[i + 1..] {
+            assert_ne!(a.name(), b.name());
+        }
+        assert_eq!(a.to_string(), a.name());
+    }
+}
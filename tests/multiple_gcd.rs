@@ -0,0 +1,134 @@
+// -------------------------------------------------------------------
+// multiple_gcd.rs -- Checks of the gcd, lcm and Bezout coefficients of
+// several values against a search over the candidates.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch12::*;
+use fm2gp::multiple_gcd::*;
+
+// Every list of length 0 to 3 of values in -12..=12, and some longer ones
+fn lists() -> Vec<Vec<i64>> {
+    let mut lists = vec![vec![]];
+    let mut shorter: Vec<Vec<i64>> = vec![vec![]];
+    for _ in 0..3 {
+        shorter = shorter
+            .iter()
+            .flat_map(|l| (-12..=12).map(move |v| [&l[..], &[v]].concat()))
+            .collect();
+        lists.extend(shorter.iter().cloned());
+    }
+    lists.push(vec![6, 10, 15]);
+    lists.push(vec![
+        2 * 3 * 5 * 7,
+        3 * 5 * 7 * 11,
+        5 * 7 * 11 * 13,
+        7 * 11 * 13 * 2,
+    ]);
+    lists.push(vec![1024, -768, 4096, 0, 96, 40]);
+    let min = i64::MIN;
+    lists.push(vec![min]);
+    lists.push(vec![0, min]);
+    lists.push(vec![min, min / 2]);
+    lists.push(vec![min, 3]);
+    lists.push(vec![3, min]);
+    lists.push(vec![6, min, 0]);
+    lists.push(vec![min, -12, 8]);
+    lists
+}
+
+// The largest d dividing every value, searched down from the smallest
+// nonzero |value|, or 0 if all are 0
+fn search_gcd(values: &[i64]) -> i128 {
+    let smallest = values
+        .iter()
+        .map(|&v| i128::from(v).abs())
+        .filter(|&v| v != 0)
+        .min();
+    match smallest {
+        None => 0,
+        Some(m) => (1..=m)
+            .rev()
+            .find(|d| values.iter().all(|&v| i128::from(v) % d == 0))
+            .unwrap(),
+    }
+}
+
+// The smallest multiple of the largest |value| that every value
+// divides, 0 if a value is 0, 1 if there are none
+fn search_lcm(values: &[i64]) -> i128 {
+    if values.contains(&0) {
+        return 0;
+    }
+    let largest = values
+        .iter()
+        .map(|&v| i128::from(v).abs())
+        .max()
+        .unwrap_or(1);
+    (1..)
+        .map(|k| k * largest)
+        .find(|m| values.iter().all(|&v| m % i128::from(v) == 0))
+        .unwrap()
+}
+
+#[test]
+fn gcd_all_matches_search() {
+    for values in lists() {
+        let expected = search_gcd(&values);
+        if expected > i128::from(i64::MAX) {
+            continue;
+        }
+        assert_eq!(
+            i128::from(gcd_all(values.clone())),
+            expected,
+            "{:?}",
+            values
+        );
+        if !values.contains(&i64::MIN) {
+            let stein = fold_gcd(values.clone(), stein_gcd);
+            assert_eq!(i128::from(stein), expected, "{:?}", values);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn gcd_all_of_the_minimum_alone_is_out_of_range() {
+    gcd_all(vec![0, i64::MIN]);
+}
+
+#[test]
+fn lcm_all_matches_search() {
+    for values in lists() {
+        let expected = search_lcm(&values);
+        let expected = if expected <= i128::from(i64::MAX) {
+            Some(expected as i64)
+        } else {
+            None
+        };
+        assert_eq!(lcm_all(values.clone()), expected, "{:?}", values);
+    }
+    assert_eq!(lcm_all(vec![3i64, i64::MIN]), None);
+    assert_eq!(lcm_all(vec![1u8 << 4, 3, 5]), Some(240));
+    assert_eq!(lcm_all(vec![1u8 << 4, 3, 7]), None);
+}
+
+#[test]
+fn extended_gcd_all_gives_bezout_coefficients() {
+    // extended_gcd works out cofactors as large as |value| / gcd, which
+    // overflow for i64::MIN
+    for values in lists()
+        .into_iter()
+        .filter(|values| !values.contains(&i64::MIN))
+    {
+        let (coefficients, gcd) = extended_gcd_all(values.clone());
+        assert_eq!(i128::from(gcd), search_gcd(&values), "{:?}", values);
+        assert_eq!(coefficients.len(), values.len());
+        let sum: i128 = coefficients
+            .iter()
+            .zip(&values)
+            .map(|(&x, &v)| i128::from(x) * i128::from(v))
+            .sum();
+        assert_eq!(sum, i128::from(gcd), "{:?} by {:?}", values, coefficients);
+    }
+}
@@ -0,0 +1,56 @@
+// -------------------------------------------------------------------
+// primes.rs -- Checks of the prime iterators against trial division.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::primes::*;
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+#[test]
+fn primes_up_to_matches_trial_division() {
+    for n in 0..2000 {
+        let expected: Vec<u64> = (0..=n).filter(|&m| is_prime(m)).collect();
+        assert_eq!(primes_up_to(n).collect::<Vec<u64>>(), expected, "n = {}", n);
+        assert_eq!(prime_pi(n), expected.len() as u64, "n = {}", n);
+    }
+}
+
+#[test]
+fn primes_below_three() {
+    assert_eq!(primes_up_to(0).count(), 0);
+    assert_eq!(primes_up_to(1).count(), 0);
+    assert_eq!(primes_up_to(2).collect::<Vec<u64>>(), vec![2]);
+    assert_eq!(prime_pi(0), 0);
+    assert_eq!(prime_pi(1), 0);
+    assert_eq!(prime_pi(2), 1);
+    assert_eq!(nth_prime(1), 2);
+    assert_eq!(nth_prime(2), 3);
+}
+
+#[test]
+fn nth_prime_matches_trial_division() {
+    let expected: Vec<u64> = (0..).filter(|&m| is_prime(m)).take(1000).collect();
+    for (k, &p) in expected.iter().enumerate() {
+        assert_eq!(nth_prime(k + 1), p, "k = {}", k + 1);
+    }
+    assert_eq!(primes().take(1000).collect::<Vec<u64>>(), expected);
+}
+
+#[test]
+fn primes_from_matches_trial_division() {
+    for lo in (0..1000).chain(1_000_000..1_000_050) {
+        let expected: Vec<u64> = (lo..).filter(|&m| is_prime(m)).take(20).collect();
+        assert_eq!(
+            primes_from(lo).take(20).collect::<Vec<u64>>(),
+            expected,
+            "lo = {}",
+            lo
+        );
+    }
+}
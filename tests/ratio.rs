@@ -0,0 +1,144 @@
+// -------------------------------------------------------------------
+// ratio.rs -- Checks of the fractions of ratio.rs against the integer
+// arithmetic of their numerators and denominators.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+extern crate num_traits;
+use fm2gp::ch07::*;
+use fm2gp::ratio::*;
+use num_traits::{Num, One, Zero};
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// Every n / d with -12 <= n, d <= 12 and d != 0, unreduced
+fn grid() -> Vec<(i64, i64)> {
+    let mut grid = Vec::new();
+    for n in -12..=12 {
+        for d in (-12..=12).filter(|&d| d != 0) {
+            grid.push((n, d));
+        }
+    }
+    grid
+}
+
+// x equals n / d
+fn equals(x: &Ratio<i64>, n: i64, d: i64) -> bool {
+    x.numer() * d == n * x.denom()
+}
+
+#[test]
+fn new_reduces_and_makes_the_denominator_positive() {
+    for (n, d) in grid() {
+        let x = Ratio::new(n, d);
+        assert!(*x.denom() > 0, "{} / {}", n, d);
+        assert_eq!(gcd(*x.numer(), *x.denom()), 1, "{} / {}", n, d);
+        assert!(equals(&x, n, d), "{} / {} = {}", n, d, x);
+        assert_eq!(Ratio::new(-n, -d), x);
+        assert_eq!(-x, Ratio::new(-n, d));
+    }
+    assert_eq!(Ratio::new(0, -5), Ratio::zero());
+    assert_eq!(Ratio::new(-7, -7), Ratio::one());
+    assert_eq!(Ratio::from_integer(-4), Ratio::new(8, -2));
+}
+
+#[test]
+#[should_panic(expected = "zero denominator")]
+fn new_rejects_a_zero_denominator() {
+    Ratio::new(1, 0);
+}
+
+#[test]
+fn arithmetic_matches_cross_multiplication() {
+    let grid = grid();
+    for &(a, b) in &grid {
+        let x = Ratio::new(a, b);
+        for &(c, d) in &grid {
+            let y = Ratio::new(c, d);
+            assert!(equals(&(x + y), a * d + c * b, b * d), "{} + {}", x, y);
+            assert!(equals(&(x - y), a * d - c * b, b * d), "{} - {}", x, y);
+            assert!(equals(&(x * y), a * c, b * d), "{} * {}", x, y);
+            if c != 0 {
+                assert!(equals(&(x / y), a * d, b * c), "{} / {}", x, y);
+                // x % y = x - y q, with q = x / y truncated as i64 does
+                let q = (x.numer() * y.denom()) / (y.numer() * x.denom());
+                assert_eq!(x % y, x - y * Ratio::from_integer(q), "{} % {}", x, y);
+            }
+        }
+    }
+}
+
+#[test]
+fn order_matches_cross_multiplication() {
+    let grid = grid();
+    for &(a, b) in &grid {
+        let x = Ratio::new(a, b);
+        for &(c, d) in &grid {
+            let y = Ratio::new(c, d);
+            // a / b < c / d, multiplied through by (b d)^2 > 0
+            let expected = (a * d * b * d).cmp(&(c * b * b * d));
+            assert_eq!(x.cmp(&y), expected, "{} against {}", x, y);
+            assert_eq!(x.partial_cmp(&y), Some(expected));
+        }
+    }
+}
+
+#[test]
+fn power_group_with_negative_exponents() {
+    assert_eq!(Ratio::new(2i64, 3).power_group(-3), Ratio::new(27, 8));
+    for (n, d) in grid().into_iter().filter(|&(n, _)| n != 0) {
+        let x = Ratio::new(n, d);
+        let mut power = Ratio::one();
+        for k in 0..5 {
+            assert_eq!(x.power_group(k), power, "{}^{}", x, k);
+            assert_eq!(x.power_group(-k), Ratio::one() / power, "{}^-{}", x, k);
+            power = power * x;
+        }
+    }
+}
+
+#[test]
+fn from_str_radix_parses_fractions_and_integers() {
+    for (n, d) in grid() {
+        let text = format!("{}/{}", n, d);
+        assert_eq!(
+            Ratio::from_str_radix(&text, 10),
+            Ok(Ratio::new(n, d)),
+            "{}",
+            text
+        );
+        let x = Ratio::new(n, d);
+        assert_eq!(Ratio::from_str_radix(&x.to_string(), 10), Ok(x));
+    }
+    assert_eq!(
+        Ratio::<i64>::from_str_radix("-5", 10),
+        Ok(Ratio::from_integer(-5))
+    );
+    assert_eq!(
+        Ratio::<i64>::from_str_radix("a/-f", 16),
+        Ok(Ratio::new(-2, 3))
+    );
+    assert_eq!(
+        Ratio::<i64>::from_str_radix("3/0", 10),
+        Err(ParseRatioError::ZeroDenominator)
+    );
+    for text in &["", "1/", "/2", "x/2", "1/2/3", "1.5"] {
+        match Ratio::<i64>::from_str_radix(text, 10) {
+            Err(ParseRatioError::Component(_)) => {}
+            result => panic!("{:?} gave {:?}", text, result),
+        }
+    }
+}
+
+#[test]
+fn display_leaves_out_a_unit_denominator() {
+    assert_eq!(Ratio::new(6i64, -4).to_string(), "-3/2");
+    assert_eq!(Ratio::new(6i64, 3).to_string(), "2");
+    assert_eq!(Ratio::new(0i64, 3).to_string(), "0");
+}
@@ -0,0 +1,93 @@
+// -------------------------------------------------------------------
+// segmented_sieve.rs -- Checks of the segmented sieve against sift.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch03::*;
+use fm2gp::segmented_sieve::*;
+
+// The primes below n, read off a sift of the whole table
+fn sift_primes(n: u64) -> Vec<u64> {
+    let len = if n < 4 { 0 } else { ((n - 2) / 2) as usize };
+    let mut table = vec![false; len];
+    sift(&mut table[..]);
+    let odd = (0..len).filter(|&i| table[i]).map(|i| 2 * i as u64 + 3);
+    (if n > 2 { vec![2] } else { vec![] })
+        .into_iter()
+        .chain(odd)
+        .collect()
+}
+
+#[test]
+fn segmented_primes_match_sift() {
+    let n = 3000;
+    let all = sift_primes(n);
+    for lo in 0..60 {
+        for &hi in &[lo, lo + 1, lo + 2, lo + 17, 100, 1000, n] {
+            let expected: Vec<u64> = all.iter().cloned().filter(|&p| lo <= p && p < hi).collect();
+            for &segment_size in &[1, 2, 7, 64, SEGMENT_SIZE] {
+                let primes: Vec<u64> =
+                    segmented_primes_with_segment_size(lo, hi, segment_size).collect();
+                assert_eq!(primes, expected, "[{}, {}) by {}", lo, hi, segment_size);
+            }
+        }
+    }
+}
+
+#[test]
+fn sift_segment_matches_sift() {
+    let mut table = vec![false; 10_000];
+    sift(&mut table[..]);
+    let primes = sieving_primes(2 * table.len() as u64 + 3);
+    for &(from, len) in &[(0, 1), (0, 100), (1, 7), (500, 333), (9_000, 1_000)] {
+        let mut segment = vec![false; len];
+        // entry i of the table stands for 2i + 3
+        sift_segment(&mut segment[..], 2 * from as u64 + 3, &primes);
+        assert_eq!(segment[..], table[from..from + len], "segment at {}", from);
+    }
+}
+
+// Trial division, for checks far beyond any table
+fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+#[test]
+fn segmented_primes_far_from_zero() {
+    let lo = 1_000_000_000_000;
+    let hi = lo + 1_000;
+    let expected: Vec<u64> = (lo..hi).filter(|&n| is_prime(n)).collect();
+    let primes: Vec<u64> = segmented_primes_with_segment_size(lo, hi, 100).collect();
+    assert_eq!(primes, expected);
+}
+
+#[test]
+fn sift_parallel_matches_sift() {
+    for &len in &[
+        0,
+        1,
+        100,
+        SEGMENT_SIZE - 1,
+        SEGMENT_SIZE,
+        SEGMENT_SIZE + 1,
+        3 * SEGMENT_SIZE + 12_345,
+        10 * SEGMENT_SIZE,
+    ] {
+        let mut expected = vec![false; len];
+        sift(&mut expected);
+        for &threads in &[1, 2, 3, 8] {
+            let mut table = vec![true; len];
+            sift_parallel(&mut table, threads);
+            assert_eq!(table, expected, "Vec<bool>, {} by {}", len, threads);
+
+            let mut bits = BitVector::new(len, false);
+            sift_parallel(&mut bits, threads);
+            assert!(
+                (0..len).all(|i| bits.get(i) == expected[i]),
+                "BitVector, {} by {}",
+                len,
+                threads
+            );
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// -------------------------------------------------------------------
+// special_numbers.rs -- Checks of the figurate number tests against
+// the numbers themselves.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::special_numbers::*;
+
+fn check_figurate<F, P>(f: F, is_f: P)
+where
+    F: Fn(i64) -> i64,
+    P: Fn(&i64) -> bool,
+{
+    let numbers: Vec<i64> = figurate_numbers(&f).take_while(|&m| m <= 10_000).collect();
+    for m in -10_000..=10_000 {
+        assert_eq!(is_f(&m), m == 0 || numbers.contains(&m), "m = {}", m);
+    }
+    assert!(!is_f(&i64::MIN));
+}
+
+#[test]
+fn is_triangular_matches_triangular() {
+    check_figurate(triangular, is_triangular);
+}
+
+#[test]
+fn is_oblong_matches_oblong() {
+    check_figurate(oblong, is_oblong);
+}
+
+#[test]
+fn is_pentagonal_matches_pentagonal() {
+    check_figurate(pentagonal, is_pentagonal);
+}
@@ -0,0 +1,107 @@
+// -------------------------------------------------------------------
+// trace.rs -- Checks of the Russian peasant traces of Chapter 2 against
+// mult_acc4 and multiply4, and of their renderings against the table of
+// Section 2.1.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch02::*;
+use fm2gp::trace::*;
+
+// Every row keeps r + n * a equal to the result, counting a in r only
+// when the row adds it
+fn check_rows(trace: &Trace<i64, i64>) {
+    for row in &trace.rows {
+        let r = row.r.unwrap_or(0);
+        let n = if row.added { row.n - 1 } else { row.n };
+        assert_eq!(row.added, row.n % 2 == 1, "{:?}", row);
+        assert_eq!(r + n * row.a, trace.result, "{:?}", row);
+    }
+    let last = trace.rows.last().unwrap();
+    assert_eq!((last.n, last.added), (1, true));
+}
+
+#[test]
+fn traces_agree_with_the_algorithms() {
+    for n in 1..200i64 {
+        for a in -50..50i64 {
+            let trace = multiply4_trace(n, a);
+            assert_eq!(trace.result, multiply4(n, a), "{} * {}", n, a);
+            assert_eq!(trace.result, n * a);
+            check_rows(&trace);
+            let first_odd = trace.rows.iter().position(|row| row.added).unwrap();
+            assert!(trace.rows[..first_odd].iter().all(|row| row.r.is_none()));
+            for &r in &[-7i64, 0, 1000] {
+                let trace = mult_acc4_trace(r, n, a);
+                assert_eq!(trace.result, mult_acc4(r, n, a), "{} + {} * {}", r, n, a);
+                assert_eq!(trace.result, r + n * a);
+                assert!(trace.rows.iter().all(|row| row.r.is_some()));
+                check_rows(&trace);
+            }
+        }
+    }
+}
+
+fn row(n: i64, a: i64, added: bool, r: Option<i64>) -> TraceRow<i64, i64> {
+    TraceRow { n, a, added, r }
+}
+
+#[test]
+fn forty_one_times_fifty_nine() {
+    // the table of Section 2.1
+    let table = vec![
+        row(41, 59, true, Some(59)),
+        row(20, 118, false, Some(59)),
+        row(10, 236, false, Some(59)),
+        row(5, 472, true, Some(531)),
+        row(2, 944, false, Some(531)),
+        row(1, 1888, true, Some(2419)),
+    ];
+    let trace = multiply4_trace(41i64, 59i64);
+    assert_eq!(trace.rows, table);
+    assert_eq!(trace.result, 2419);
+    assert_eq!(mult_acc4_trace(0i64, 41i64, 59i64), trace);
+
+    assert_eq!(
+        trace.to_text(),
+        "   n     a     r\n\
+         + 41    59    59\n\
+         \x20 20   118    59\n\
+         \x20 10   236    59\n\
+         +  5   472   531\n\
+         \x20  2   944   531\n\
+         +  1  1888  2419\n\
+         result = 2419\n"
+    );
+    assert_eq!(trace.to_string(), trace.to_text());
+    assert_eq!(
+        trace.to_markdown(),
+        "| n | a | added | r |\n\
+         |--:|--:|:-:|--:|\n\
+         | 41 | 59 | + | 59 |\n\
+         | 20 | 118 |  | 59 |\n\
+         | 10 | 236 |  | 59 |\n\
+         | 5 | 472 | + | 531 |\n\
+         | 2 | 944 |  | 531 |\n\
+         | 1 | 1888 | + | 2419 |\n\
+         \n\
+         **result = 2419**\n"
+    );
+}
+
+#[test]
+fn json_marks_the_rows_before_the_accumulator_with_null() {
+    assert_eq!(
+        multiply4_trace(12i64, 5i64).to_json(),
+        "{\"rows\": [\
+         {\"n\": 12, \"a\": 5, \"added\": false, \"r\": null}, \
+         {\"n\": 6, \"a\": 10, \"added\": false, \"r\": null}, \
+         {\"n\": 3, \"a\": 20, \"added\": true, \"r\": 20}, \
+         {\"n\": 1, \"a\": 40, \"added\": true, \"r\": 60}], \
+         \"result\": 60}"
+    );
+    assert_eq!(
+        mult_acc4_trace(-1i64, 1i64, -3i64).to_json(),
+        "{\"rows\": [{\"n\": 1, \"a\": -3, \"added\": true, \"r\": -4}], \"result\": -4}"
+    );
+}
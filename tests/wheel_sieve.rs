@@ -0,0 +1,45 @@
+// -------------------------------------------------------------------
+// wheel_sieve.rs -- Checks of the wheel sieves against primes_up_to.
+// -------------------------------------------------------------------
+
+extern crate fm2gp;
+use fm2gp::ch03::*;
+use fm2gp::primes::*;
+use fm2gp::wheel_sieve::*;
+
+fn check_wheel<F>(wheel: &Wheel, sift: F)
+where
+    F: Fn(&mut dyn SieveStorage),
+{
+    for n in 0..3000 {
+        let expected: Vec<u64> = primes_up_to(n).collect();
+        let len = wheel.table_len(n);
+
+        let mut table = vec![false; len];
+        sift(&mut table);
+        assert_eq!(wheel.primes_in(&table, n), expected, "Vec<bool>, n = {}", n);
+
+        let mut bits = BitVector::new(len, false);
+        sift(&mut bits);
+        assert_eq!(wheel.primes_in(&bits, n), expected, "BitVector, n = {}", n);
+    }
+}
+
+#[test]
+fn sift30_matches_primes_up_to() {
+    check_wheel(&wheel30(), |table| sift30(table));
+}
+
+#[test]
+fn sift210_matches_primes_up_to() {
+    check_wheel(&wheel210(), |table| sift210(table));
+}
+
+#[test]
+fn index_inverts_value() {
+    for wheel in &[wheel30(), wheel210()] {
+        for i in 0..1000 {
+            assert_eq!(wheel.index(wheel.value(i)), i);
+        }
+    }
+}